@@ -16,7 +16,7 @@ use crate::client::blob_storage::{BlobStorage, BlobStorageDryRun};
 
 use safe_nd::{
     Blob, BlobAddress, BlobRead, BlobWrite, Cmd, DataCmd, DataQuery, DebitAgreementProof,
-    PrivateBlob, PublicBlob, PublicKey, Query, QueryResponse,
+    Error as SndError, PrivateBlob, PublicBlob, PublicKey, Query, QueryResponse,
 };
 use self_encryption::{DataMap, SelfEncryptor};
 
@@ -65,25 +65,7 @@ impl Client {
     {
         trace!("Fetch Blob");
 
-        if let Some(data) = self.blob_cache.lock().await.get_mut(&address) {
-            trace!("Blob found in cache.");
-            return Ok(data.clone());
-        }
-
-        let res = self
-            .send_query(Query::Data(DataQuery::Blob(BlobRead::Get(address))))
-            .await?;
-        let data: Blob = match res {
-            QueryResponse::GetBlob(res) => res.map_err(CoreError::from),
-            _ => return Err(CoreError::ReceivedUnexpectedEvent),
-        }?;
-
-        // Put to cache
-        let _ = self
-            .blob_cache
-            .lock()
-            .await
-            .put(*data.address(), data.clone());
+        let data = self.get_raw_blob(address).await?;
 
         let is_published = data.is_pub();
 
@@ -137,13 +119,11 @@ impl Client {
         //---------------------------------
         let msg_contents = wrap_blob_write(
             BlobWrite::New(data_to_write_to_network.clone()),
-            payment_proof.clone(),
+            payment_proof,
         );
         let message = Self::create_cmd_message(msg_contents);
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        let _ = self.apply_write_payment_to_local_actor(payment_proof).await;
-
         Ok(data_to_write_to_network)
     }
 
@@ -179,6 +159,40 @@ impl Client {
     /// #  Ok(())} );}
     /// ```
     pub async fn delete_blob(&mut self, address: BlobAddress) -> Result<(), CoreError> {
+        self.delete_private_blob(address).await
+    }
+
+    /// Delete a Private Blob from the network, paying for it as a write.
+    ///
+    /// Published Blobs can never be deleted: attempting it fails with
+    /// `CoreError::DataError(SndError::InvalidOperation)`, before anything is paid or sent.
+    ///
+    /// # Examples
+    ///
+    /// Remove private data
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, Blob, PrivateBlob, PublicKey};
+    /// use std::str::FromStr;
+    /// use threshold_crypto::SecretKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key.clone())).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let data = b"some private data".to_vec();
+    /// let some_blob_for_storage = Blob::Private(PrivateBlob::new(data, PublicKey::from(secret_key.public_key())));
+    /// let blob = client.store_blob(some_blob_for_storage).await?;
+    ///
+    /// client.delete_private_blob(*blob.address()).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn delete_private_blob(&mut self, address: BlobAddress) -> Result<(), CoreError> {
+        if address.is_pub() {
+            return Err(CoreError::DataError(SndError::InvalidOperation));
+        }
+
         // --------------------------
         // Payment for PUT
         // --------------------------
@@ -187,18 +201,144 @@ impl Client {
         //---------------------------------
         // The _actual_ message
         //---------------------------------
-        let msg_contents =
-            wrap_blob_write(BlobWrite::DeletePrivate(address), payment_proof.clone());
+        let msg_contents = wrap_blob_write(BlobWrite::DeletePrivate(address), payment_proof);
         let message = Self::create_cmd_message(msg_contents);
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        // Don't serve the deleted blob from the cache anymore
+        let _ = self.blob_cache.lock().await.pop(&address);
+
+        Ok(())
+    }
+
+    /// Store data of any size on the network, self encrypting it into chunks which are each
+    /// stored as a blob, along with the resulting data map.
+    ///
+    /// This returns the address of the data map, from which the data can be read back with
+    /// `read_large_blob`. Every chunk and the data map are paid for as a write.
+    ///
+    /// # Examples
+    ///
+    /// Store a large file
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let data = vec![0_u8; 5 * 1024 * 1024];
+    /// let address = client.store_large_blob(&data, true).await?;
+    ///
+    /// let read_data = client.read_large_blob(address).await?;
+    /// assert_eq!(read_data, data);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn store_large_blob(
+        &mut self,
+        data: &[u8],
+        published: bool,
+    ) -> Result<BlobAddress, CoreError> {
+        trace!("Store large Blob of {} bytes", data.len());
+        self.check_writes_allowed()?;
+
+        // Chunks are stored as they are produced
+        let blob_storage = BlobStorage::new(self.clone(), published);
+        let self_encryptor = SelfEncryptor::new(blob_storage, DataMap::None)
+            .map_err(|e| CoreError::from(format!("Self encryption error: {}", e)))?;
+        self_encryptor
+            .write(data, 0)
+            .await
+            .map_err(|e| CoreError::from(format!("Self encryption error: {}", e)))?;
+        let (data_map, _) = self_encryptor
+            .close()
+            .await
+            .map_err(|e| CoreError::from(format!("Self encryption error: {}", e)))?;
+
+        let value = serialize(&DataTypeEncoding::Serialised(serialize(&data_map)?))?;
+        let data_map_blob = self.pack(self.public_key().await, value, published).await?;
+        let address = *data_map_blob.address();
+
+        self.store_raw_blob(data_map_blob).await?;
+
+        Ok(address)
+    }
+
+    /// Read back data stored with `store_large_blob`, reassembling it from the chunks
+    /// referenced by the data map at the given address.
+    ///
+    /// # Examples
+    ///
+    /// Read a large file
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::BlobAddress;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let address = BlobAddress::Public(XorName::random());
+    /// let mut client = Client::new(None).await?;
+    ///
+    /// let _data = client.read_large_blob(address).await?;
+    /// # Ok(())} );}
+    /// ```
+    pub async fn read_large_blob(&mut self, address: BlobAddress) -> Result<Vec<u8>, CoreError> {
+        trace!("Read large Blob at {:?}", address);
+
+        let data_map_blob = self.get_raw_blob(address).await?;
+        self.extract_blob_data(data_map_blob, None, None).await
     }
 
     // --------------------------------------------
     // ---------- Private helpers -----------------
     // --------------------------------------------
 
+    // Get a blob as it's stored on the network, from the cache if we have it.
+    pub(crate) async fn get_raw_blob(&mut self, address: BlobAddress) -> Result<Blob, CoreError> {
+        if let Some(data) = self.blob_cache.lock().await.get(&address) {
+            trace!("Blob found in cache.");
+            return Ok(data.clone());
+        }
+
+        let res = self
+            .send_query(Query::Data(DataQuery::Blob(BlobRead::Get(address))))
+            .await?;
+        let data: Blob = match res {
+            QueryResponse::GetBlob(res) => res.map_err(CoreError::from),
+            other => return Err(CoreError::unexpected_query_response("GetBlob", &other)),
+        }?;
+
+        // Put to cache
+        let _ = self
+            .blob_cache
+            .lock()
+            .await
+            .put(*data.address(), data.clone());
+
+        Ok(data)
+    }
+
+    // Store a blob on the network as it is, without self encrypting it.
+    pub(crate) async fn store_raw_blob(&mut self, data: Blob) -> Result<(), CoreError> {
+        // --------------------------
+        // Payment for PUT
+        // --------------------------
+        let payment_proof = self.create_write_payment_proof().await?;
+
+        //---------------------------------
+        // The _actual_ message
+        //---------------------------------
+        let msg_contents = wrap_blob_write(BlobWrite::New(data), payment_proof);
+        let message = Self::create_cmd_message(msg_contents);
+        let _ = self.connection_manager.send_cmd(&message).await?;
+
+        Ok(())
+    }
+
     // use self_encryption to generated an encrypted blob stored at the data map
     async fn self_encrypt_blob(&mut self, data: Blob) -> Result<Blob, CoreError> {
         let blob_storage = BlobStorageDryRun::new(self.clone(), data.is_pub());
@@ -324,7 +464,7 @@ pub mod exported_tests {
         generate_random_vector,
         test_utils::{calculate_new_balance, gen_bls_keypair},
     };
-    use safe_nd::{Error as SndError, Money, PrivateBlob, PublicBlob};
+    use safe_nd::{Money, PrivateBlob, PublicBlob};
     use std::str::FromStr;
     use unwrap::unwrap;
 
@@ -367,6 +507,57 @@ pub mod exported_tests {
         Ok(())
     }
 
+    // Test reading back the content of a pub blob, from the network then from the cache.
+    pub async fn pub_blob_read_back_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let value = generate_random_vector::<u8>(1024);
+        let blob = Blob::Public(PublicBlob::new(value.clone()));
+        let address = *blob.address();
+        let _ = client.store_blob(blob).await?;
+
+        let fetched_data = client.get_blob(address, None, None).await?;
+        assert_eq!(*fetched_data.value(), value);
+
+        // Now served from the cache, with the same content
+        let fetched_data = client.get_blob(address, None, None).await?;
+        assert_eq!(*fetched_data.value(), value);
+
+        Ok(())
+    }
+
+    // Test a cached blob is read without querying the network, until it's deleted.
+    pub async fn blob_cache_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let value = generate_random_vector::<u8>(1024);
+        let blob = Blob::Public(PublicBlob::new(value.clone()));
+        let pub_address = *client.store_blob(blob).await?.address();
+
+        let _ = client.get_blob(pub_address, None, None).await?;
+        let queries_sent = client.connection_manager.queries_sent();
+        let fetched_data = client.get_blob(pub_address, None, None).await?;
+        assert_eq!(*fetched_data.value(), value);
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent);
+
+        let owner = client.public_key().await;
+        let blob = Blob::Private(PrivateBlob::new(value, owner));
+        let priv_address = *client.store_blob(blob).await?.address();
+
+        let _ = client.get_blob(priv_address, None, None).await?;
+        assert!(client.blob_cache.lock().await.contains(&priv_address));
+
+        client.delete_blob(priv_address).await?;
+        assert!(!client.blob_cache.lock().await.contains(&priv_address));
+
+        let queries_sent = client.connection_manager.queries_sent();
+        let res = client.get_blob(priv_address, None, None).await;
+        assert!(res.is_err(), "Private blob still exists after deletion");
+        assert!(client.connection_manager.queries_sent() > queries_sent);
+
+        Ok(())
+    }
+
     // Test putting, getting, and deleting unpub blob.
     pub async fn unpub_blob_test() -> Result<(), CoreError> {
         println!("blob_Test________");
@@ -454,6 +645,64 @@ pub mod exported_tests {
         Ok(())
     }
 
+    pub async fn delete_private_blob_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let blob = Blob::Private(PrivateBlob::new(
+            generate_random_vector::<u8>(10),
+            client.public_key().await,
+        ));
+        let address = *client.store_blob(blob).await?.address();
+        let _ = client.get_blob(address, None, None).await?;
+
+        let balance_before_delete = client.get_balance().await?;
+        client.delete_private_blob(address).await?;
+        let balance_after_delete = client.get_balance().await?;
+        assert_eq!(
+            balance_after_delete,
+            calculate_new_balance(balance_before_delete, Some(1), None)
+        );
+
+        let res = client.get_blob(address, None, None).await;
+        assert!(res.is_err(), "Private blob still exists after deletion");
+
+        Ok(())
+    }
+
+    pub async fn delete_public_blob_is_rejected() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let blob = Blob::Public(PublicBlob::new(generate_random_vector::<u8>(10)));
+        let address = *client.store_blob(blob).await?.address();
+
+        let balance_before_delete = client.get_balance().await?;
+        match client.delete_private_blob(address).await {
+            Err(CoreError::DataError(SndError::InvalidOperation)) => (),
+            res => panic!("Unexpected result deleting a public blob: {:?}", res),
+        }
+        // Nothing was paid for the rejected deletion
+        assert_eq!(client.get_balance().await?, balance_before_delete);
+
+        // And the data is still there
+        let _ = client.get_blob(address, None, None).await?;
+
+        Ok(())
+    }
+
+    pub async fn store_and_read_5mb_large_blob() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let data = generate_random_vector::<u8>(5 * 1024 * 1024);
+        let address = client.store_large_blob(&data, true).await?;
+        assert!(address.is_pub());
+
+        let read_data = client.read_large_blob(address).await?;
+        assert_eq!(read_data.len(), data.len());
+        assert!(read_data == data);
+
+        Ok(())
+    }
+
     // Test creating and retrieving a 1kb blob.
     pub async fn create_and_retrieve_1kb_pub_unencrypted() -> Result<(), CoreError> {
         let size = 1024;
@@ -712,6 +961,11 @@ mod tests {
         exported_tests::pub_blob_test().await
     }
 
+    #[tokio::test]
+    async fn pub_blob_read_back_test() -> Result<(), CoreError> {
+        exported_tests::pub_blob_read_back_test().await
+    }
+
     // Test putting, getting, and deleting unpub blob.
     #[tokio::test]
     async fn unpub_blob_test() -> Result<(), CoreError> {
@@ -723,6 +977,26 @@ mod tests {
         exported_tests::blob_deletions_should_cost_put_price().await
     }
 
+    #[tokio::test]
+    async fn blob_cache_test() -> Result<(), CoreError> {
+        exported_tests::blob_cache_test().await
+    }
+
+    #[tokio::test]
+    async fn delete_private_blob_test() -> Result<(), CoreError> {
+        exported_tests::delete_private_blob_test().await
+    }
+
+    #[tokio::test]
+    async fn delete_public_blob_is_rejected() -> Result<(), CoreError> {
+        exported_tests::delete_public_blob_is_rejected().await
+    }
+
+    #[tokio::test]
+    async fn store_and_read_5mb_large_blob() -> Result<(), CoreError> {
+        exported_tests::store_and_read_5mb_large_blob().await
+    }
+
     #[tokio::test]
     async fn create_and_retrieve_1kb_pub_unencrypted() -> Result<(), CoreError> {
         exported_tests::create_and_retrieve_1kb_pub_unencrypted().await
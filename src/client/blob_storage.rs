@@ -55,7 +55,8 @@ impl Storage for BlobStorage {
             BlobAddress::Private(name)
         };
 
-        match self.client.get_blob(address, None, None).await {
+        // Chunks are stored as they are, so are fetched as such
+        match self.client.get_raw_blob(address).await {
             Ok(data) => Ok(data.value().clone()),
             Err(error) => Err(BlobStorageError::from(error)),
         }
@@ -68,8 +69,9 @@ impl Storage for BlobStorage {
         } else {
             PrivateBlob::new(data, self.client.public_key().await).into()
         };
-        match self.client.store_blob(blob).await {
-            Ok(_r) => Ok(()),
+        // Stored as it is, at the address `generate_address` gives for it
+        match self.client.store_raw_blob(blob).await {
+            Ok(()) => Ok(()),
             Err(error) => Err(BlobStorageError::from(error)),
         }
     }
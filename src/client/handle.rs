@@ -0,0 +1,127 @@
+// Copyright 2020 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::Client;
+use crate::errors::CoreError;
+use safe_nd::{Money, PublicKey, Sequence, SequenceAddress, SequenceEntry};
+use std::sync::Arc;
+
+/// A cloneable handle to a single `Client`, to be shared by the tasks of an app.
+///
+/// Cloning the handle only clones an `Arc`. All the clones share the client's connection to the
+/// network, its transfer actor and its local CRDT replica. The debits of the transfer actor are
+/// made one at a time, so concurrent transfers from several clones are neither lost nor sent
+/// out of order.
+///
+/// The most common operations are available on the handle itself, and any other one on the
+/// `Client` returned by `client`, which shares the same state.
+///
+/// # Examples
+///
+/// ```no_run
+/// # extern crate tokio; use safe_core::CoreError;
+/// use safe_core::client::ClientHandle;
+/// use safe_core::Client;
+/// use safe_nd::{Money, PublicKey};
+/// use std::str::FromStr;
+/// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+/// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+/// let handle = ClientHandle::new(Client::new(None).await?);
+///
+/// let (first, second) = (handle.clone(), handle.clone());
+/// let _ = futures::join!(
+///     first.send_money(pk, Money::from_str("1")?),
+///     second.send_money(pk, Money::from_str("1")?),
+/// );
+/// # Ok(()) } ); }
+/// ```
+#[derive(Clone)]
+pub struct ClientHandle {
+    client: Arc<Client>,
+}
+
+impl ClientHandle {
+    /// Create a handle to share the given client.
+    pub fn new(client: Client) -> Self {
+        Self {
+            client: Arc::new(client),
+        }
+    }
+
+    /// Get a `Client` sharing the state of the handle, to use any of the client APIs.
+    pub fn client(&self) -> Client {
+        (*self.client).clone()
+    }
+
+    /// Get the public key of the client.
+    pub async fn public_key(&self) -> PublicKey {
+        self.client.public_key().await
+    }
+
+    /// Get the balance of the client from the local actor, as with `Client::get_local_balance`.
+    pub async fn get_local_balance(&self) -> Money {
+        self.client.get_local_balance().await
+    }
+
+    /// Get the balance of the client from the network, as with `Client::get_balance`.
+    pub async fn get_balance(&self) -> Result<Money, CoreError> {
+        self.client().get_balance().await
+    }
+
+    /// Send money to another PublicKey, as with `Client::send_money`.
+    pub async fn send_money(&self, to: PublicKey, amount: Money) -> Result<(), CoreError> {
+        self.client().send_money(to, amount).await
+    }
+
+    /// Get a Sequence, as with `Client::get_sequence`.
+    pub async fn get_sequence(&self, address: SequenceAddress) -> Result<Sequence, CoreError> {
+        self.client().get_sequence(address).await
+    }
+
+    /// Append an entry to a Sequence, as with `Client::append_to_sequence`.
+    pub async fn append_to_sequence(
+        &self,
+        address: SequenceAddress,
+        entry: SequenceEntry,
+    ) -> Result<(), CoreError> {
+        self.client().append_to_sequence(address, entry).await
+    }
+}
+
+#[cfg(all(test, feature = "simulated-payouts"))]
+mod tests {
+    use super::*;
+    use crate::crypto::shared_box;
+    use futures::future::join_all;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn client_handle_sends_money_concurrently_from_clones() -> Result<(), CoreError> {
+        let recipient = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        let handle = ClientHandle::new(Client::new(None).await?);
+
+        let transfers = (0..5).map(|_| {
+            let handle = handle.clone();
+            async move { handle.send_money(recipient, Money::from_str("1")?).await }
+        });
+        for res in join_all(transfers).await {
+            res?;
+        }
+
+        // initial 10 on creation from farming simulation minus 5 transfers of 1
+        assert_eq!(handle.get_local_balance().await, Money::from_str("5")?);
+        assert_eq!(handle.get_balance().await?, Money::from_str("5")?);
+        assert_eq!(
+            handle.client().get_balance_for(recipient).await?,
+            Money::from_str("5")?
+        );
+
+        Ok(())
+    }
+}
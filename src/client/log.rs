@@ -0,0 +1,141 @@
+// Copyright 2020 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::Client;
+use crate::errors::CoreError;
+use futures::Stream;
+use safe_nd::{SequenceAddress, SequenceEntry, SequenceIndex};
+use xor_name::XorName;
+
+/// An append-only log, stored as a Sequence on the network.
+///
+/// Entries are appended with the client's cached replica of the Sequence, and its permissions
+/// are checked before paying for the append, as with `Client::append_to_sequence`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+/// use safe_core::client::Log;
+/// use safe_core::Client;
+/// use safe_nd::Money;
+/// use xor_name::XorName;
+/// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+/// let mut client = Client::new(None).await?;
+/// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+/// let mut log = Log::create(client, XorName::random(), 15000).await?;
+///
+/// let _ = log.append(b"first".to_vec()).await?;
+/// let _ = log.append(b"second".to_vec()).await?;
+/// assert_eq!(log.tail(1).await?, vec![b"second".to_vec()]);
+/// # Ok(()) } ); }
+/// ```
+#[derive(Clone)]
+pub struct Log {
+    client: Client,
+    address: SequenceAddress,
+}
+
+impl Log {
+    /// Create a new log as a Private Sequence which only the client can read and append to.
+    pub async fn create(mut client: Client, name: XorName, tag: u64) -> Result<Self, CoreError> {
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+        Ok(Self::open(client, address))
+    }
+
+    /// Open the log stored as the Sequence at the given address.
+    pub fn open(client: Client, address: SequenceAddress) -> Self {
+        Self { client, address }
+    }
+
+    /// Get the address of the Sequence the log is stored as.
+    pub fn address(&self) -> SequenceAddress {
+        self.address
+    }
+
+    /// Append an entry to the log, returning its index.
+    pub async fn append(&mut self, entry: SequenceEntry) -> Result<u64, CoreError> {
+        self.client
+            .append_to_sequence_indexed(self.address, entry)
+            .await
+    }
+
+    /// Get the number of entries in the log.
+    pub async fn len(&mut self) -> Result<u64, CoreError> {
+        self.client.get_sequence_entries_count(self.address).await
+    }
+
+    /// Check whether the log has no entries yet.
+    pub async fn is_empty(&mut self) -> Result<bool, CoreError> {
+        Ok(self.len().await? == 0)
+    }
+
+    /// Get the last `n` entries of the log, oldest first, or all of them if there are fewer.
+    pub async fn tail(&mut self, n: u64) -> Result<Vec<SequenceEntry>, CoreError> {
+        let len = self.len().await?;
+        if n == 0 || len == 0 {
+            return Ok(Vec::new());
+        }
+
+        let start = len.saturating_sub(n);
+        self.client
+            .get_sequence_range(
+                self.address,
+                (
+                    SequenceIndex::FromStart(start),
+                    SequenceIndex::FromStart(len),
+                ),
+            )
+            .await
+    }
+
+    /// Get a stream of all the entries of the log along with their indices, as with
+    /// `Client::sequence_entry_stream`.
+    pub async fn stream(
+        &mut self,
+    ) -> Result<impl Stream<Item = Result<(u64, SequenceEntry), CoreError>>, CoreError> {
+        self.client.sequence_entry_stream(self.address).await
+    }
+}
+
+#[cfg(all(test, feature = "simulated-payouts"))]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn log_appends_and_reads_its_tail() -> Result<(), CoreError> {
+        let client = Client::new(None).await?;
+        let mut log = Log::create(client, XorName::random(), 15000).await?;
+        assert!(log.is_empty().await?);
+        assert_eq!(log.tail(2).await?, Vec::<SequenceEntry>::new());
+
+        assert_eq!(log.append(b"one".to_vec()).await?, 0);
+        assert_eq!(log.append(b"two".to_vec()).await?, 1);
+        assert_eq!(log.append(b"three".to_vec()).await?, 2);
+
+        assert_eq!(log.len().await?, 3);
+        assert_eq!(log.tail(2).await?, vec![b"two".to_vec(), b"three".to_vec()]);
+        assert_eq!(log.tail(10).await?.len(), 3);
+
+        let streamed: Vec<_> = log.stream().await?.try_collect().await?;
+        assert_eq!(
+            streamed,
+            vec![
+                (0, b"one".to_vec()),
+                (1, b"two".to_vec()),
+                (2, b"three".to_vec())
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -49,7 +49,7 @@ impl Client {
                 Map::Unseq(data) => Ok(data),
                 Map::Seq(_) => Err(CoreError::ReceivedUnexpectedData),
             }),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("GetMap", &other)),
         }
     }
 
@@ -78,7 +78,7 @@ impl Client {
                     MapValue::Unseq(_) => Err(CoreError::ReceivedUnexpectedData),
                 })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("GetMapValue", &other)),
         }
     }
 
@@ -107,7 +107,7 @@ impl Client {
                     MapValue::Seq(_) => Err(CoreError::ReceivedUnexpectedData),
                 })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("GetMapValue", &other)),
         }
     }
 
@@ -126,7 +126,7 @@ impl Client {
                 Map::Seq(data) => Ok(data),
                 Map::Unseq(_) => Err(CoreError::ReceivedUnexpectedData),
             }),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("GetMap", &other)),
         }
     }
 
@@ -186,7 +186,7 @@ impl Client {
                     _ => Err(CoreError::ReceivedUnexpectedData),
                 })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("GetMapShell", &other)),
         }
     }
 
@@ -214,7 +214,7 @@ impl Client {
                     _ => Err(CoreError::ReceivedUnexpectedData),
                 })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("GetMapShell", &other)),
         }
     }
 
@@ -230,7 +230,10 @@ impl Client {
             .await?
         {
             QueryResponse::GetMapVersion(res) => res.map_err(CoreError::from),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "GetMapVersion",
+                &other,
+            )),
         }
     }
 
@@ -259,7 +262,10 @@ impl Client {
                         MapEntries::Seq(_) => Err(CoreError::ReceivedUnexpectedData),
                     })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "ListMapEntries",
+                &other,
+            )),
         }
     }
 
@@ -288,7 +294,10 @@ impl Client {
                         MapEntries::Unseq(_) => Err(CoreError::ReceivedUnexpectedData),
                     })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "ListMapEntries",
+                &other,
+            )),
         }
     }
 
@@ -307,7 +316,7 @@ impl Client {
             .await?
         {
             QueryResponse::ListMapKeys(res) => res.map_err(CoreError::from),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response("ListMapKeys", &other)),
         }?;
 
         Ok(res)
@@ -338,7 +347,10 @@ impl Client {
                         MapValues::Unseq(_) => Err(CoreError::ReceivedUnexpectedData),
                     })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "ListMapValues",
+                &other,
+            )),
         }
     }
 
@@ -367,7 +379,10 @@ impl Client {
                         MapValues::Seq(_) => Err(CoreError::ReceivedUnexpectedData),
                     })
             }
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "ListMapValues",
+                &other,
+            )),
         }
     }
 
@@ -394,7 +409,10 @@ impl Client {
             .await?
         {
             QueryResponse::ListMapUserPermissions(res) => res.map_err(CoreError::from),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "ListMapUserPermissions",
+                &other,
+            )),
         }
     }
 
@@ -413,7 +431,10 @@ impl Client {
             .await?
         {
             QueryResponse::ListMapPermissions(res) => res.map_err(CoreError::from),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
+            other => Err(CoreError::unexpected_query_response(
+                "ListMapPermissions",
+                &other,
+            )),
         }?;
 
         Ok(res)
@@ -476,11 +497,11 @@ impl Client {
         //---------------------------------
         // The _actual_ message
         //---------------------------------
-        let msg_contents = wrap_map_write(MapWrite::Delete(address), payment_proof.clone());
+        let msg_contents = wrap_map_write(MapWrite::Delete(address), payment_proof);
         let message = Self::create_cmd_message(msg_contents);
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 
     /// Delete mutable data user permission
@@ -505,14 +526,14 @@ impl Client {
                 user,
                 version,
             },
-            payment_proof.clone(),
+            payment_proof,
         );
 
         let message = Self::create_cmd_message(msg_contents);
 
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 
     /// Set mutable data user permissions
@@ -539,7 +560,7 @@ impl Client {
                 permissions,
                 version,
             },
-            payment_proof.clone(),
+            payment_proof,
         );
 
         let message = Self::create_cmd_message(msg_contents);
@@ -547,7 +568,7 @@ impl Client {
         // TODO what will be the correct reponse here?... We have it validated, so registered?
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 
     /// Mutate mutable data user entries
@@ -565,13 +586,12 @@ impl Client {
         // The _actual_ message
         //---------------------------------
 
-        let msg_contents =
-            wrap_map_write(MapWrite::Edit { address, changes }, payment_proof.clone());
+        let msg_contents = wrap_map_write(MapWrite::Edit { address, changes }, payment_proof);
 
         let message = Self::create_cmd_message(msg_contents);
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 
     /// Store a new public mutable data object
@@ -585,11 +605,11 @@ impl Client {
         //---------------------------------
         // The _actual_ message
         //---------------------------------
-        let msg_contents = wrap_map_write(MapWrite::New(data), payment_proof.clone());
+        let msg_contents = wrap_map_write(MapWrite::New(data), payment_proof);
         let message = Self::create_cmd_message(msg_contents);
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 }
 
@@ -21,36 +21,63 @@ pub mod transfer_actor;
 /// Sequence APIs
 pub mod sequence_apis;
 
+/// Export and import of a client's session state
+pub mod session;
+
+/// Cloneable handle to a client shared by several tasks
+pub mod handle;
+/// Append-only log stored as a Sequence
+pub mod log;
+
 mod blob_storage;
 
 // safe-transfers wrapper
+pub use self::handle::ClientHandle;
+pub use self::log::Log;
 pub use self::map_info::MapInfo;
-pub use self::transfer_actor::{ClientTransferValidator, SafeTransferActor};
+pub use self::transfer_actor::{
+    BalanceBreakdown, ClientTransferValidator, IncomingTransfer, SafeTransferActor, SentTransfer,
+    TransferStatus, TransferValidationConfig,
+};
 
 use crate::config_handler::Config;
-use crate::connection_manager::ConnectionManager;
+use crate::connection_manager::{
+    ConnectionManager, ConnectionStatus, MessageTransport, ReconnectConfig,
+};
 use crate::errors::CoreError;
+#[cfg(feature = "metrics")]
+use crate::metrics::{Metrics, MetricsSnapshot};
 
+use ::log::{debug, error, info, trace, warn};
 use crdts::Dot;
-use futures::lock::Mutex;
-use log::{debug, error, info, trace, warn};
+use futures::{
+    channel::oneshot,
+    future::{BoxFuture, FutureExt},
+    lock::Mutex,
+};
 use lru::LruCache;
 use quic_p2p::Config as QuicP2pConfig;
 use rand::thread_rng;
 use safe_nd::{
-    Blob, BlobAddress, ClientFullId, Cmd, Message, MessageId, Money, PublicId, PublicKey, Query,
-    QueryResponse, Sequence, SequenceAddress,
+    Blob, BlobAddress, ClientFullId, Cmd, DebitAgreementProof, Message, MessageId, Money, PublicId,
+    PublicKey, Query, QueryResponse, ReplicaEvent, Sequence, SequenceAddress, SequenceWriteOp,
+    Signature, TransferId,
 };
 
 #[cfg(feature = "simulated-payouts")]
 use std::str::FromStr;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex as StdMutex};
 
 use xor_name::XorName;
 
-use std::{collections::HashSet, net::SocketAddr};
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
 use threshold_crypto::{PublicKeySet, SecretKey};
+use tokio::{sync::broadcast, time::delay_for};
 
 /// Capacity of the immutable data cache.
 pub const IMMUT_DATA_CACHE_SIZE: usize = 300;
@@ -58,22 +85,220 @@ pub const IMMUT_DATA_CACHE_SIZE: usize = 300;
 /// Capacity of the Sequence CRDT local replica size.
 pub const SEQUENCE_CRDT_REPLICA_SIZE: usize = 300;
 
+/// How long a Sequence fetched from the network is read from the local CRDT replica
+/// before being fetched again.
+pub const SEQUENCE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// How long since it was fetched a private Sequence of the local CRDT replica is appended to
+/// without checking the network still holds it.
+pub const SEQUENCE_DELETED_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long the balance fetched from the network is served by `Client::get_balance_cached`.
+pub const BALANCE_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// How many times a query failing on a transient network error is sent again.
+pub const QUERY_RETRIES: usize = 2;
+
+/// Delay before the first retry of a query, doubling with every retry.
+pub const QUERY_RETRY_BACKOFF: Duration = Duration::from_millis(200);
+
+/// How long a command or query sent to the network may take before failing.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
 /// Expected cost of mutation operations.
 pub const COST_OF_PUT: Money = Money::from_nano(1);
 
+/// Maximum size in bytes of a Sequence entry accepted by the network.
+pub const MAX_SEQUENCE_ENTRY_SIZE: usize = 1024 * 1024;
+
+/// Maximum size in bytes of a memo attached to a transfer.
+pub const MAX_TRANSFER_MEMO_SIZE: usize = 1024;
+
+/// Number of transfer idempotency keys tracked, the least recently used being forgotten first.
+pub const TRANSFER_IDEMPOTENCY_KEYS_CAPACITY: usize = 1000;
+
+/// Number of balance changes buffered for each balance change subscriber.
+pub const BALANCE_CHANGE_CHANNEL_SIZE: usize = 100;
+
+/// Number of incoming transfers buffered for each incoming transfer subscriber.
+pub const INCOMING_TRANSFER_CHANNEL_SIZE: usize = 100;
+
 /// Return the `crust::Config` associated with the `crust::Service` (if any).
 pub fn bootstrap_config() -> Result<HashSet<SocketAddr>, CoreError> {
     Ok(Config::new().quic_p2p.hard_coded_contacts)
 }
 
+/// Tunables of a `Client`, passed to `Client::with_config`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClientConfig {
+    /// Maximum number of Sequences kept in the local CRDT replica, the least recently used
+    /// being evicted first. Defaults to `SEQUENCE_CRDT_REPLICA_SIZE`.
+    pub sequence_cache_capacity: usize,
+    /// How long since it was last fetched a Sequence is read from the local CRDT replica,
+    /// after which it's fetched from the network again. A TTL of zero disables the local
+    /// replica entirely. Defaults to `SEQUENCE_CACHE_TTL`.
+    pub sequence_cache_ttl: Duration,
+    /// How long since it was fetched a private Sequence of the local CRDT replica is appended
+    /// to without first checking the network still holds it, which costs a query. Past it, an
+    /// append to a Sequence deleted meanwhile fails with `CoreError::SequenceDeleted` without
+    /// paying. An interval of zero checks before every append. Appends made with
+    /// `Client::append_to_sequence_nowait` are never checked. Defaults to
+    /// `SEQUENCE_DELETED_CHECK_INTERVAL`.
+    pub sequence_deleted_check_interval: Duration,
+    /// Whether the Sequences stored by the client are put in the local CRDT replica, which
+    /// write-heavy apps never reading them back can turn off to save memory. Defaults to `true`.
+    pub cache_on_write: bool,
+    /// Largest entry, in bytes, appended to a Sequence. Larger ones are rejected before paying
+    /// for the append. Defaults to `MAX_SEQUENCE_ENTRY_SIZE`.
+    pub max_entry_size: usize,
+    /// How long since it was fetched the balance is served by `Client::get_balance_cached`.
+    /// A TTL of zero disables caching it. Defaults to `BALANCE_CACHE_TTL`.
+    pub balance_cache_ttl: Duration,
+    /// How many times a read query is sent again after failing because the connection was
+    /// lost or the request timed out. Defaults to `QUERY_RETRIES`.
+    pub query_retries: usize,
+    /// Delay before the first retry of a read query, doubling with every retry.
+    /// Defaults to `QUERY_RETRY_BACKOFF`.
+    pub query_retry_backoff: Duration,
+    /// How long any command or query sent to the network may take, after which it fails with
+    /// `CoreError::RequestTimeout`. No timeout if `None`. Defaults to `DEFAULT_REQUEST_TIMEOUT`.
+    pub default_request_timeout: Option<Duration>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            sequence_cache_capacity: SEQUENCE_CRDT_REPLICA_SIZE,
+            sequence_cache_ttl: SEQUENCE_CACHE_TTL,
+            sequence_deleted_check_interval: SEQUENCE_DELETED_CHECK_INTERVAL,
+            cache_on_write: true,
+            max_entry_size: MAX_SEQUENCE_ENTRY_SIZE,
+            balance_cache_ttl: BALANCE_CACHE_TTL,
+            query_retries: QUERY_RETRIES,
+            query_retry_backoff: QUERY_RETRY_BACKOFF,
+            default_request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
+        }
+    }
+}
+
+// Whether the request failing with this error may succeed if sent again as is.
+fn is_transient(error: &CoreError) -> bool {
+    matches!(error, CoreError::ConnectionLost | CoreError::RequestTimeout)
+}
+
+// Run the operation, trying again after an exponential backoff each time
+// it fails on a transient error, up to `retries` times.
+async fn with_retry<C, T, Op>(
+    state: &mut C,
+    retries: usize,
+    backoff: Duration,
+    mut operation: Op,
+) -> Result<T, CoreError>
+where
+    Op: for<'a> FnMut(&'a mut C) -> BoxFuture<'a, Result<T, CoreError>>,
+{
+    let mut attempt = 0;
+    let mut delay = backoff;
+    loop {
+        match operation(state).await {
+            Err(error) if attempt < retries && is_transient(&error) => {
+                attempt += 1;
+                warn!(
+                    "Request failed with {}, retrying in {:?} (attempt {} of {})",
+                    error, delay, attempt, retries
+                );
+                delay_for(delay).await;
+                delay = delay.checked_mul(2).unwrap_or(delay);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Source of the current time, which can be replaced to control how old cached data is.
+pub(crate) trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
 /// Client object
 #[derive(Clone)]
 pub struct Client {
     full_id: ClientFullId,
+    /// Public key of `full_id`, which never changes
+    public_key: PublicKey,
     blob_cache: Arc<Mutex<LruCache<BlobAddress, Blob>>>,
-    /// Sequence CRDT replica
-    sequence_cache: Arc<Mutex<LruCache<SequenceAddress, Sequence>>>,
+    /// Sequence CRDT replica, along with when each Sequence was last fetched
+    sequence_cache: Arc<Mutex<LruCache<SequenceAddress, (Sequence, Instant)>>>,
+    /// How long the Sequences in the local replica are read before being fetched again
+    sequence_cache_ttl: Duration,
+    /// How long since it was fetched a private Sequence is appended to without checking
+    /// the network still holds it
+    sequence_deleted_check_interval: Duration,
+    /// Whether the Sequences stored by this client are put in the local replica
+    cache_on_write: bool,
+    /// Largest entry appended to a Sequence
+    max_entry_size: usize,
+    /// Readers waiting on a fetch of a Sequence from the network already in flight
+    in_flight_sequence_fetches:
+        Arc<StdMutex<HashMap<SequenceAddress, Vec<oneshot::Sender<Sequence>>>>>,
+    /// Appends sent to the network, until its replicas are seen to have applied them
+    pending_sequence_ops: Arc<Mutex<HashMap<SequenceAddress, Vec<SequenceWriteOp<Vec<u8>>>>>>,
+    clock: Arc<dyn Clock>,
+    /// Sequences stored by this client, and not deleted since. Locked without awaiting anything
+    created_sequences: Arc<StdMutex<Vec<SequenceAddress>>>,
+    /// Sequences each appended to along with a primary one. Locked without awaiting anything
+    sequence_mirrors: Arc<StdMutex<HashMap<SequenceAddress, SequenceAddress>>>,
+    /// Errors of the writes sent in the background, until drained. Locked without awaiting anything
+    background_errors: Arc<StdMutex<Vec<CoreError>>>,
+    /// Completion of the last write sent in the background, which the next one waits for
+    last_background_write: Arc<StdMutex<Option<oneshot::Receiver<()>>>>,
     transfer_actor: Arc<Mutex<SafeTransferActor<ClientTransferValidator>>>,
+    /// Memos attached to the transfers sent by this client
+    transfer_memos: Arc<Mutex<HashMap<TransferId, Vec<u8>>>>,
+    /// Idempotency keys of the last transfers, mapped to the transfer id once completed
+    transfer_idempotency_keys: Arc<Mutex<LruCache<Vec<u8>, Option<TransferId>>>>,
+    /// Debit proofs received from the network, awaiting to be picked up by their transfer
+    debit_cache: Arc<Mutex<HashMap<TransferId, DebitAgreementProof>>>,
+    /// How long to wait for transfers to be validated, and how many times to retry
+    validation_config: TransferValidationConfig,
+    /// Transfers validated by the network but not registered yet
+    pending_transfers: Arc<Mutex<HashMap<TransferId, DebitAgreementProof>>>,
+    /// Transfers sent for validation, still waiting for their debit proof
+    transfers_awaiting_validation: Arc<Mutex<HashMap<TransferId, Money>>>,
+    /// Transfers cancelled while awaiting their validation, with their validation request
+    cancelled_transfers: Arc<Mutex<HashMap<TransferId, (Message, Money)>>>,
+    /// Held while transferring money, so this client and its clones debit one transfer at a time
+    debit_lock: Arc<Mutex<()>>,
+    /// Version of the transfer history which follows the last event retrieved
+    transfer_history_version: Arc<Mutex<usize>>,
+    /// Events of the transfer history retrieved so far, in order
+    transfer_history: Arc<Mutex<Vec<ReplicaEvent>>>,
+    /// Our balance last fetched from the network, along with when it was
+    balance_cache: Arc<Mutex<Option<(Money, Instant)>>>,
+    /// How long the cached balance is served before being fetched again
+    balance_cache_ttl: Duration,
+    /// How many times, and after how long, read queries failing on transient errors are retried
+    query_retries: usize,
+    query_retry_backoff: Duration,
+    /// Counters of the network operations made by this client and its clones
+    #[cfg(feature = "metrics")]
+    metrics: Arc<Metrics>,
+    /// Notifies the subscribers of changes to the local balance
+    balance_change_sender: broadcast::Sender<Money>,
+    /// Notifies the subscribers of the transfers credited to this client
+    incoming_transfer_sender: broadcast::Sender<IncomingTransfer>,
+    /// Whether writes and Money transfers are forbidden
+    read_only: bool,
+    /// Whether writes and Money transfers are only applied locally
+    dry_run: bool,
     replicas_pk_set: PublicKeySet,
     simulated_farming_payout_dot: Dot<PublicKey>,
     connection_manager: ConnectionManager,
@@ -104,29 +329,159 @@ impl Client {
     /// # Ok(()) } ); }
     /// ```
     pub async fn new(sk: Option<SecretKey>) -> Result<Self, CoreError> {
-        crate::utils::init_log();
+        Self::new_with_bootstrap(sk, Vec::new()).await
+    }
+
+    /// Create a Safe Network client instance, as with `Client::new`, but bootstrapping to the network
+    /// through the given contacts. The default contacts (from the config file) are used if none are passed.
+    ///
+    /// # Examples
+    ///
+    /// Create a random client connecting to a local network
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use std::net::SocketAddr;
+    ///
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let contact: SocketAddr = "127.0.0.1:12000".parse().map_err(|_| CoreError::from("Invalid address"))?;
+    /// let mut client = Client::new_with_bootstrap(None, vec![contact]).await?;
+    /// let _some_balance = client.get_balance().await?;
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn new_with_bootstrap(
+        sk: Option<SecretKey>,
+        bootstrap_nodes: Vec<SocketAddr>,
+    ) -> Result<Self, CoreError> {
+        Self::create(sk, bootstrap_nodes, false, ClientConfig::default()).await
+    }
+
+    /// Create a Safe Network client instance, as with `Client::new`, but tuned with the given config.
+    ///
+    /// # Examples
+    ///
+    /// Create a random client keeping few Sequences in its local replica
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::{Client, ClientConfig};
+    ///
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let config = ClientConfig {
+    ///     sequence_cache_capacity: 10,
+    ///     ..Default::default()
+    /// };
+    /// let mut client = Client::with_config(None, config).await?;
+    /// let _some_balance = client.get_balance().await?;
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn with_config(
+        sk: Option<SecretKey>,
+        config: ClientConfig,
+    ) -> Result<Self, CoreError> {
+        Self::create(sk, Vec::new(), false, config).await
+    }
 
+    /// Create a Safe Network client instance which can only perform Read operations.
+    ///
+    /// Any write or Money transfer attempted with it fails with `CoreError::OperationForbidden`,
+    /// without reaching the network. A random client created this way gets no simulated payout.
+    ///
+    /// # Examples
+    ///
+    /// Create a random read-only client
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, PublicKey};
+    /// use std::str::FromStr;
+    ///
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new_read_only(None).await?;
+    /// let _some_balance = client.get_balance().await?;
+    ///
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// assert!(client.send_money(pk, Money::from_str("1")?).await.is_err());
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn new_read_only(sk: Option<SecretKey>) -> Result<Self, CoreError> {
+        Self::create(sk, Vec::new(), true, ClientConfig::default()).await
+    }
+
+    async fn create(
+        sk: Option<SecretKey>,
+        bootstrap_nodes: Vec<SocketAddr>,
+        read_only: bool,
+        config: ClientConfig,
+    ) -> Result<Self, CoreError> {
         #[cfg(feature = "simulated-payouts")]
-        let mut is_random_client = true;
+        let is_random_client = sk.is_none();
 
-        let full_id = match sk {
-            Some(sk) => {
-                #[cfg(feature = "simulated-payouts")]
-                {
-                    is_random_client = false;
-                }
+        let mut full_client = Self::connect(sk, bootstrap_nodes, read_only, config).await?;
 
-                ClientFullId::from(sk)
-            }
-            None => {
-                let mut rng = thread_rng();
-                ClientFullId::new_bls(&mut rng)
+        #[cfg(feature = "simulated-payouts")]
+        {
+            // only trigger simulated payouts on new _random_ clients which can spend them
+            if is_random_client && !read_only {
+                // we're testing, and currently a lot of tests expect 10 money to start
+                let _ = full_client
+                    .trigger_simulated_farming_payout(Money::from_str("10")?)
+                    .await?;
             }
-        };
+        }
+
+        let _ = full_client.get_history().await;
+
+        //Start listening for Events
+        full_client.listen_on_network().await;
+
+        Ok(full_client)
+    }
+
+    // Bootstrap to the network and set up a client, without any transfer history yet
+    async fn connect(
+        sk: Option<SecretKey>,
+        bootstrap_nodes: Vec<SocketAddr>,
+        read_only: bool,
+        config: ClientConfig,
+    ) -> Result<Self, CoreError> {
+        crate::utils::init_log();
+
+        let full_id = client_full_id(sk);
 
         // Create the connection manager
-        let mut connection_manager =
-            attempt_bootstrap(&Config::new().quic_p2p, full_id.clone()).await?;
+        let qp2p_config = bootstrap_qp2p_config(Config::new().quic_p2p, bootstrap_nodes);
+        let connection_manager = attempt_bootstrap(&qp2p_config, full_id.clone()).await?;
+
+        Self::set_up(full_id, connection_manager, read_only, config).await
+    }
+
+    /// Create a client delivering all its messages to the given transport instead of the network,
+    /// such as the `MockTransport` of `utils::test_utils`, to test it without a live network.
+    ///
+    /// The transport must answer the query for the replica keys sent while setting the client up.
+    /// Unlike `Client::new`, no transfer history is retrieved and network events aren't listened to.
+    pub async fn with_transport(
+        sk: Option<SecretKey>,
+        transport: Arc<dyn MessageTransport>,
+        config: ClientConfig,
+    ) -> Result<Self, CoreError> {
+        crate::utils::init_log();
+
+        let full_id = client_full_id(sk);
+        let connection_manager =
+            ConnectionManager::with_transport(Config::new().quic_p2p, full_id.clone(), transport)?;
+
+        Self::set_up(full_id, connection_manager, false, config).await
+    }
+
+    // Set up a client sending its messages through the given connection manager
+    async fn set_up(
+        full_id: ClientFullId,
+        mut connection_manager: ConnectionManager,
+        read_only: bool,
+        config: ClientConfig,
+    ) -> Result<Self, CoreError> {
+        connection_manager.set_request_timeout(config.default_request_timeout);
 
         let simulated_farming_payout_dot = Dot::new(*full_id.public_key(), 0);
 
@@ -135,39 +490,58 @@ impl Client {
 
         let validator = ClientTransferValidator {};
 
+        #[cfg(feature = "metrics")]
+        let metrics = connection_manager.metrics();
+
         let transfer_actor = Arc::new(Mutex::new(SafeTransferActor::new(
             full_id.keypair().clone(),
             replicas_pk_set.clone(),
             validator,
         )));
 
-        let mut full_client = Self {
+        Ok(Self {
             connection_manager,
+            public_key: *full_id.public_key(),
             full_id,
             transfer_actor,
             replicas_pk_set,
             simulated_farming_payout_dot,
             blob_cache: Arc::new(Mutex::new(LruCache::new(IMMUT_DATA_CACHE_SIZE))),
-            sequence_cache: Arc::new(Mutex::new(LruCache::new(SEQUENCE_CRDT_REPLICA_SIZE))),
-        };
-
-        #[cfg(feature = "simulated-payouts")]
-        {
-            // only trigger simulated payouts on new _random_ clients
-            if is_random_client {
-                // we're testing, and currently a lot of tests expect 10 money to start
-                let _ = full_client
-                    .trigger_simulated_farming_payout(Money::from_str("10")?)
-                    .await?;
-            }
-        }
-
-        let _ = full_client.get_history().await;
-
-        //Start listening for Events
-        full_client.listen_on_network().await;
-
-        Ok(full_client)
+            sequence_cache: Arc::new(Mutex::new(LruCache::new(config.sequence_cache_capacity))),
+            sequence_cache_ttl: config.sequence_cache_ttl,
+            sequence_deleted_check_interval: config.sequence_deleted_check_interval,
+            cache_on_write: config.cache_on_write,
+            max_entry_size: config.max_entry_size,
+            in_flight_sequence_fetches: Arc::new(StdMutex::new(HashMap::new())),
+            pending_sequence_ops: Arc::new(Mutex::new(HashMap::new())),
+            clock: Arc::new(SystemClock),
+            created_sequences: Arc::new(StdMutex::new(Vec::new())),
+            sequence_mirrors: Arc::new(StdMutex::new(HashMap::new())),
+            background_errors: Arc::new(StdMutex::new(Vec::new())),
+            last_background_write: Arc::new(StdMutex::new(None)),
+            transfer_memos: Arc::new(Mutex::new(HashMap::new())),
+            transfer_idempotency_keys: Arc::new(Mutex::new(LruCache::new(
+                TRANSFER_IDEMPOTENCY_KEYS_CAPACITY,
+            ))),
+            debit_cache: Arc::new(Mutex::new(HashMap::new())),
+            validation_config: TransferValidationConfig::default(),
+            pending_transfers: Arc::new(Mutex::new(HashMap::new())),
+            transfers_awaiting_validation: Arc::new(Mutex::new(HashMap::new())),
+            cancelled_transfers: Arc::new(Mutex::new(HashMap::new())),
+            debit_lock: Arc::new(Mutex::new(())),
+            transfer_history_version: Arc::new(Mutex::new(0)),
+            transfer_history: Arc::new(Mutex::new(Vec::new())),
+            balance_cache: Arc::new(Mutex::new(None)),
+            balance_cache_ttl: config.balance_cache_ttl,
+            query_retries: config.query_retries,
+            query_retry_backoff: config.query_retry_backoff,
+            #[cfg(feature = "metrics")]
+            metrics,
+            balance_change_sender: broadcast::channel(BALANCE_CHANGE_CHANNEL_SIZE).0,
+            incoming_transfer_sender: broadcast::channel(INCOMING_TRANSFER_CHANNEL_SIZE).0,
+            read_only,
+            dry_run: false,
+        })
     }
 
     /// Listen to network events.
@@ -190,11 +564,14 @@ impl Client {
                             match self.handle_validation_event(event).await {
                                 Ok(proof) => {
                                     match proof {
-                                        Some(_debit) => {
-                                            // TODO: store response against correlation ID,
-                                            // use this id for retrieval in write apis.
-                                            info!("DO SOMETHING WITH PROOF");
-                                            // let _ = self.debit_cache.insert(debit.id(), debit);
+                                        Some(debit) => {
+                                            // Keep it for the transfer awaiting its validation
+                                            info!("Debit proof received for {:?}", debit.id());
+                                            let _ = self
+                                                .debit_cache
+                                                .lock()
+                                                .await
+                                                .insert(debit.id(), debit);
                                         }
                                         None => warn!("Handled a validation Event"),
                                     }
@@ -211,16 +588,150 @@ impl Client {
             }
         }
     }
-    /*
-        async fn check_debit_cache(&mut self, id: TransferId) -> DebitAgreementProof {
-            loop {
-                match self.debit_cache.get(&id) {
-                    Some(proof) => return proof.clone(),
-                    None => (),
-                }
-            }
+
+    /// Set how long to wait for a transfer to be validated by the network, and how many times its
+    /// validation request is re-sent before giving up with a `CoreError::RequestTimeout`.
+    ///
+    /// By default there is no timeout, and transfers wait for their validation indefinitely.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::client::TransferValidationConfig;
+    /// use safe_core::Client;
+    /// use std::time::Duration;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// client.set_transfer_validation_config(TransferValidationConfig {
+    ///     timeout: Some(Duration::from_secs(30)),
+    ///     max_retries: 3,
+    /// });
+    /// # Ok(()) } ); }
+    /// ```
+    pub fn set_transfer_validation_config(&mut self, config: TransferValidationConfig) {
+        self.validation_config = config;
+    }
+
+    /// Set how reconnecting to the network is attempted when the connection is lost,
+    /// before requests fail with a `CoreError::ConnectionLost`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::{Client, ReconnectConfig};
+    /// use std::time::Duration;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// client.set_reconnect_config(ReconnectConfig {
+    ///     base_delay: Duration::from_secs(1),
+    ///     max_delay: Duration::from_secs(30),
+    ///     max_attempts: 10,
+    /// });
+    /// # Ok(()) } ); }
+    /// ```
+    pub fn set_reconnect_config(&mut self, config: ReconnectConfig) {
+        self.connection_manager.set_reconnect_config(config);
+    }
+
+    /// Enable or disable the dry-run mode.
+    ///
+    /// In dry-run mode, Sequence writes are applied to the local CRDT replica, but they are neither paid for
+    /// nor sent to the network. Money transfers are signed but not sent, leaving the balance untouched,
+    /// as the local actor can only apply them once validated by the network.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{SequenceUser, SequencePubUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+    /// let _ = perms.insert(SequenceUser::Key(owner), SequencePubUserPermissions::new(true, true));
+    /// let address = client.store_public_sequence(None, XorName::random(), 10, owner, perms).await?;
+    ///
+    /// client.set_dry_run(true);
+    /// let balance = client.get_local_balance().await;
+    /// client.append_to_sequence(address, b"Local only".to_vec()).await?;
+    /// assert_eq!(client.get_local_balance().await, balance);
+    /// # Ok(()) } ); }
+    /// ```
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.dry_run = enabled;
+    }
+
+    /// Get the current values of the counters of the network operations made by this client,
+    /// along with all its clones.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.get_balance().await?;
+    /// assert!(client.metrics_snapshot().queries_sent > 0);
+    /// # Ok(()) } ); }
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    /// Report the health of the client's connection to the network: whether it is connected,
+    /// to how many Elders, and when a request last succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// let status = client.connection_status().await;
+    /// assert!(status.connected);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn connection_status(&self) -> ConnectionStatus {
+        self.connection_manager.status().await
+    }
+
+    /// Disconnect the client from the network, after registering any transfer left pending.
+    ///
+    /// Any later request made with this client, or any of its clones, fails with `CoreError::NotConnected`.
+    /// Disconnecting an already disconnected client does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// client.disconnect().await?;
+    /// assert!(client.get_balance().await.is_err());
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn disconnect(&mut self) -> Result<(), CoreError> {
+        if self.connection_manager.is_disconnected() {
+            return Ok(());
         }
-    */
+
+        // Don't lose the transfers validated but not registered yet
+        for debit_proof in self.pending_transfers().await {
+            self.register_pending_transfer(debit_proof).await?;
+        }
+
+        self.connection_manager.disconnect();
+        Ok(())
+    }
 
     /// Return the client's FullId.
     ///
@@ -271,9 +782,125 @@ impl Client {
     /// # Ok(()) } ); }
     /// ```
     pub async fn public_key(&self) -> PublicKey {
-        let id = self.full_id().await;
+        self.public_key_sync()
+    }
+
+    /// Return the client's PublicKey, as with `public_key` but without awaiting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// let _pk = client.public_key_sync();
+    /// # Ok(()) } ); }
+    /// ```
+    pub fn public_key_sync(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Sign arbitrary data with the client's key, e.g. to prove its identity off the network.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// let data = b"some data";
+    /// let signature = client.sign(data).await;
+    /// assert!(Client::verify(&client.public_key_sync(), data, &signature));
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn sign(&self, data: &[u8]) -> Signature {
+        self.full_id.sign(data)
+    }
+
+    /// Replace the key the client signs with, moving its whole balance to the new key.
+    ///
+    /// The balance known to the local actor is sent to the new key with `send_money`, after
+    /// which the client reconnects to the network with the new identity, and a new transfer
+    /// actor is keyed to it and synced with its history. Clones of the client made before
+    /// keep using the old key.
+    ///
+    /// Should reconnecting with the new key fail once the balance was moved to it,
+    /// `CoreError::KeyRotationIncomplete` is returned. The client keeps the old key, and rotating
+    /// to the same key again completes the rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use threshold_crypto::SecretKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let balance = client.get_local_balance().await;
+    ///
+    /// client.rotate_key(SecretKey::random()).await?;
+    /// assert_eq!(client.get_local_balance().await, balance);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn rotate_key(&mut self, new_secret: SecretKey) -> Result<(), CoreError> {
+        self.check_writes_allowed()?;
+
+        let full_id = ClientFullId::from(new_secret);
+        let public_key = *full_id.public_key();
+        info!(
+            "Rotating key from {:?} to {:?}",
+            self.public_key, public_key
+        );
+
+        // Our actor can only send from the old key, so the balance is moved first
+        let balance = self.get_local_balance().await;
+        if balance > Money::from_nano(0) {
+            self.send_money(public_key, balance).await?;
+        }
 
-        *id.public_key()
+        if let Err(error) = self
+            .connection_manager
+            .switch_identity(full_id.clone())
+            .await
+        {
+            if balance == Money::from_nano(0) {
+                return Err(error);
+            }
+            error!(
+                "Balance moved to {:?}, but switching to that key failed: {}",
+                public_key, error
+            );
+            return Err(CoreError::KeyRotationIncomplete {
+                new_key: public_key,
+                reason: error.to_string(),
+            });
+        }
+
+        self.transfer_actor = Arc::new(Mutex::new(SafeTransferActor::new(
+            full_id.keypair().clone(),
+            self.replicas_pk_set.clone(),
+            ClientTransferValidator {},
+        )));
+        self.simulated_farming_payout_dot = Dot::new(public_key, 0);
+        self.transfer_history_version = Arc::new(Mutex::new(0));
+        self.transfer_history = Arc::new(Mutex::new(Vec::new()));
+        self.debit_cache = Arc::new(Mutex::new(HashMap::new()));
+        self.pending_transfers = Arc::new(Mutex::new(HashMap::new()));
+        self.transfers_awaiting_validation = Arc::new(Mutex::new(HashMap::new()));
+        self.cancelled_transfers = Arc::new(Mutex::new(HashMap::new()));
+        self.full_id = full_id;
+        self.public_key = public_key;
+        self.invalidate_balance_cache().await;
+
+        // Pick up the balance we just sent to ourselves
+        self.get_history().await
+    }
+
+    /// Check the data was signed with the secret key of the given PublicKey.
+    pub fn verify(public_key: &PublicKey, data: &[u8], signature: &Signature) -> bool {
+        public_key.verify(signature, data).is_ok()
     }
 
     /// Send a Query to the network and await a response
@@ -283,7 +910,46 @@ impl Client {
         debug!("Sending QueryRequest: {:?}", query);
 
         let message = Self::create_query_message(query);
-        self.connection_manager.send_query(&message).await
+        let (retries, backoff) = (self.query_retries, self.query_retry_backoff);
+        self.send_query_with_retry(&message, retries, backoff).await
+    }
+
+    // Send a Query Message, sending it again up to `retries` times should it fail on a
+    // transient network error. Only meant for queries, which are idempotent.
+    async fn send_query_with_retry(
+        &mut self,
+        message: &Message,
+        retries: usize,
+        backoff: Duration,
+    ) -> Result<QueryResponse, CoreError> {
+        with_retry(&mut self.connection_manager, retries, backoff, |cm| {
+            let message = message.clone();
+            async move { cm.send_query(&message).await }.boxed()
+        })
+        .await
+    }
+
+    // Send a Cmd Message, sending it again up to `retries` times should it fail on a transient
+    // network error. Only meant for commands the network ignores once already applied.
+    pub(crate) async fn send_cmd_with_retry(
+        &mut self,
+        message: &Message,
+        retries: usize,
+        backoff: Duration,
+    ) -> Result<(), CoreError> {
+        with_retry(&mut self.connection_manager, retries, backoff, |cm| {
+            let message = message.clone();
+            async move { cm.send_cmd(&message).await }.boxed()
+        })
+        .await
+    }
+
+    // Fail writes and Money transfers attempted with a read-only client
+    pub(crate) fn check_writes_allowed(&self) -> Result<(), CoreError> {
+        if self.read_only {
+            return Err(CoreError::OperationForbidden);
+        }
+        Ok(())
     }
 
     // Build and sign Cmd Message Envelope
@@ -291,7 +957,6 @@ impl Client {
         let random_xor = XorName::random();
         let id = MessageId(random_xor);
         trace!("Creating cmd message with id: {:?}", id);
-        println!("cmd msg id: {:?}", id);
 
         Message::Cmd {
             cmd: msg_contents,
@@ -312,6 +977,28 @@ impl Client {
     }
 }
 
+// Identity of a client with the given secret key, or a random one.
+fn client_full_id(sk: Option<SecretKey>) -> ClientFullId {
+    match sk {
+        Some(sk) => ClientFullId::from(sk),
+        None => {
+            let mut rng = thread_rng();
+            ClientFullId::new_bls(&mut rng)
+        }
+    }
+}
+
+// Use the given bootstrap contacts in place of the configured ones, unless there are none.
+fn bootstrap_qp2p_config(
+    mut qp2p_config: QuicP2pConfig,
+    bootstrap_nodes: Vec<SocketAddr>,
+) -> QuicP2pConfig {
+    if !bootstrap_nodes.is_empty() {
+        qp2p_config.hard_coded_contacts = bootstrap_nodes.into_iter().collect();
+    }
+    qp2p_config
+}
+
 /// Utility function that bootstraps a client to the network. If there is a failure then it retries.
 /// After a maximum of three attempts if the boostrap process still fails, then an error is returned.
 pub async fn attempt_bootstrap(
@@ -342,6 +1029,68 @@ pub async fn attempt_bootstrap(
 pub mod exported_tests {
     use super::*;
     use crate::crypto::shared_box;
+    use async_trait::async_trait;
+    use safe_nd::{PublicBlob, TransferCmd};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use tracing::{span, Event, Metadata, Subscriber};
+
+    // Records the names of the spans created while it's the default subscriber
+    #[derive(Default)]
+    struct SpanRecorder {
+        names: Arc<StdMutex<Vec<&'static str>>>,
+        next_id: AtomicU64,
+    }
+
+    impl Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            if let Ok(mut names) = self.names.lock() {
+                names.push(span.metadata().name());
+            }
+            span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    // Delivers the messages to the network through the given connection manager, but
+    // disconnects the client once a transfer is registered, as if it lost its connection then
+    struct DisconnectingTransport {
+        connection_manager: Mutex<ConnectionManager>,
+        client_connection: Mutex<Option<ConnectionManager>>,
+    }
+
+    #[async_trait]
+    impl MessageTransport for DisconnectingTransport {
+        async fn send_cmd(&self, msg: &Message) -> Result<(), CoreError> {
+            self.connection_manager.lock().await.send_cmd(msg).await?;
+            if let Message::Cmd {
+                cmd: Cmd::Transfer(TransferCmd::RegisterTransfer(_)),
+                ..
+            } = msg
+            {
+                if let Some(client_connection) = self.client_connection.lock().await.as_mut() {
+                    client_connection.disconnect();
+                }
+            }
+            Ok(())
+        }
+
+        async fn send_query(&self, msg: &Message) -> Result<QueryResponse, CoreError> {
+            self.connection_manager.lock().await.send_query(msg).await
+        }
+    }
 
     pub async fn client_creation() -> Result<(), CoreError> {
         let _transfer_actor = Client::new(None).await?;
@@ -355,12 +1104,267 @@ pub mod exported_tests {
 
         Ok(())
     }
+
+    pub async fn client_public_key_sync_matches_async() -> Result<(), CoreError> {
+        let (sk, pk) = shared_box::gen_bls_keypair();
+        let client = Client::new(Some(sk)).await?;
+
+        assert_eq!(client.public_key_sync(), PublicKey::Bls(pk));
+        assert_eq!(client.public_key_sync(), client.public_key().await);
+
+        Ok(())
+    }
+
+    pub async fn client_can_sign_and_verify_data() -> Result<(), CoreError> {
+        let client = Client::new(None).await?;
+        let public_key = client.public_key_sync();
+
+        let data = b"proof of identity".to_vec();
+        let signature = client.sign(&data).await;
+        assert!(Client::verify(&public_key, &data, &signature));
+
+        // Tampered data, or another key, fails verification
+        let mut tampered_data = data.clone();
+        tampered_data[0] ^= 1;
+        assert!(!Client::verify(&public_key, &tampered_data, &signature));
+        let other_key = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        assert!(!Client::verify(&other_key, &data, &signature));
+
+        Ok(())
+    }
+
+    pub async fn client_can_rotate_its_key() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let old_public_key = client.public_key_sync();
+        let balance = client.get_balance().await?;
+
+        let (new_sk, new_pk) = shared_box::gen_bls_keypair();
+        client.rotate_key(new_sk).await?;
+
+        let new_public_key = PublicKey::Bls(new_pk);
+        assert_eq!(client.public_key_sync(), new_public_key);
+        assert_eq!(client.get_local_balance().await, balance);
+        assert_eq!(client.get_balance().await?, balance);
+        assert_eq!(client.get_balance_for(new_public_key).await?, balance);
+        assert_eq!(
+            client.get_balance_for(old_public_key).await?,
+            Money::from_nano(0)
+        );
+
+        Ok(())
+    }
+
+    pub async fn client_reports_balance_moved_when_key_rotation_fails() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let old_public_key = client.public_key_sync();
+        let balance = client.get_balance().await?;
+
+        let transport = Arc::new(DisconnectingTransport {
+            connection_manager: Mutex::new(client.connection_manager.clone()),
+            client_connection: Mutex::new(None),
+        });
+        client.connection_manager = ConnectionManager::with_transport(
+            Config::new().quic_p2p,
+            client.full_id.clone(),
+            transport.clone(),
+        )?;
+        *transport.client_connection.lock().await = Some(client.connection_manager.clone());
+
+        let (new_sk, new_pk) = shared_box::gen_bls_keypair();
+        let new_public_key = PublicKey::Bls(new_pk);
+        match client.rotate_key(new_sk).await {
+            Err(CoreError::KeyRotationIncomplete { new_key, .. }) => {
+                assert_eq!(new_key, new_public_key)
+            }
+            res => panic!("Unexpected result rotating the key: {:?}", res),
+        }
+
+        // The balance is at the new key, which the client doesn't use
+        assert_eq!(client.public_key_sync(), old_public_key);
+        let mut other_client = Client::new(None).await?;
+        assert_eq!(other_client.get_balance_for(new_public_key).await?, balance);
+
+        Ok(())
+    }
+
+    pub async fn client_creation_with_bootstrap_contacts() -> Result<(), CoreError> {
+        // Nothing is listening on this address
+        let unreachable: SocketAddr = "127.0.0.1:1"
+            .parse()
+            .map_err(|_| CoreError::from("Invalid socket address"))?;
+
+        let qp2p_config = bootstrap_qp2p_config(Config::new().quic_p2p, vec![unreachable]);
+        assert_eq!(
+            qp2p_config.hard_coded_contacts,
+            vec![unreachable].into_iter().collect()
+        );
+
+        // With no contacts, the configured ones are kept
+        let default_config = Config::new().quic_p2p;
+        assert_eq!(
+            bootstrap_qp2p_config(default_config.clone(), Vec::new()),
+            default_config
+        );
+
+        // The client only tries to bootstrap through the unreachable contact
+        let res = Client::new_with_bootstrap(None, vec![unreachable]).await;
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    pub async fn read_only_client_can_read_but_not_write() -> Result<(), CoreError> {
+        let mut client = Client::new_read_only(None).await?;
+
+        // Reads work normally, and there's no money to spend
+        assert_eq!(client.get_balance().await?, Money::from_str("0")?);
+
+        let (_sk, pk) = shared_box::gen_bls_keypair();
+        match client
+            .send_money(PublicKey::Bls(pk), Money::from_str("1")?)
+            .await
+        {
+            Err(CoreError::OperationForbidden) => (),
+            res => panic!("Unexpected result for a read-only client: {:?}", res),
+        }
+
+        let blob = Blob::Public(PublicBlob::new(b"read only".to_vec()));
+        match client.store_blob(blob).await {
+            Err(CoreError::OperationForbidden) => (),
+            res => panic!("Unexpected result for a read-only client: {:?}", res),
+        }
+
+        Ok(())
+    }
+
+    pub async fn client_cannot_be_used_after_disconnect() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        client.disconnect().await?;
+        // Disconnecting twice is fine
+        client.disconnect().await?;
+
+        match client.get_balance().await {
+            Err(CoreError::NotConnected) => Ok(()),
+            res => panic!("Unexpected result after disconnecting: {:?}", res),
+        }
+    }
+
+    pub async fn client_emits_spans_for_writes_and_transfers() -> Result<(), CoreError> {
+        let recorder = SpanRecorder::default();
+        let names = recorder.names.clone();
+        let _guard = tracing::subscriber::set_default(recorder);
+
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        let (_sk, to) = shared_box::gen_bls_keypair();
+        client
+            .send_money(PublicKey::from(to), Money::from_nano(1))
+            .await?;
+
+        let names = names
+            .lock()
+            .map_err(|_| CoreError::from("Span recorder poisoned"))?;
+        for name in &[
+            "pay_and_write_sequence_to_network",
+            "append_to_sequence",
+            "send_money",
+        ] {
+            assert!(names.contains(name), "No {} span emitted", name);
+        }
+
+        Ok(())
+    }
+
+    pub async fn client_reports_its_connection_status() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let _ = client.get_balance().await?;
+        let status = client.connection_status().await;
+        assert!(status.connected);
+        assert!(status.peer_count > 0);
+        assert!(status.last_success.is_some());
+
+        client.disconnect().await?;
+        let status = client.connection_status().await;
+        assert!(!status.connected);
+        assert_eq!(status.peer_count, 0);
+
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "simulated-payouts"))]
 mod tests {
-    use super::exported_tests;
+    use super::{exported_tests, with_retry};
     use crate::CoreError;
+    use futures::future::FutureExt;
+    use std::time::Duration;
+
+    // Fails the first `failures` queries with the given error, then returns the data.
+    struct FlakyQuery {
+        failures: usize,
+        error: fn() -> CoreError,
+        sends: usize,
+    }
+
+    impl FlakyQuery {
+        async fn send(&mut self) -> Result<Vec<u8>, CoreError> {
+            self.sends += 1;
+            if self.sends <= self.failures {
+                Err((self.error)())
+            } else {
+                Ok(b"data".to_vec())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn query_succeeds_after_transient_failures() -> Result<(), CoreError> {
+        let mut query = FlakyQuery {
+            failures: 2,
+            error: || CoreError::RequestTimeout,
+            sends: 0,
+        };
+
+        let data = with_retry(&mut query, 2, Duration::from_millis(1), |query| {
+            query.send().boxed()
+        })
+        .await?;
+
+        assert_eq!(data, b"data".to_vec());
+        assert_eq!(query.sends, 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_is_not_retried_on_semantic_errors() -> Result<(), CoreError> {
+        let mut query = FlakyQuery {
+            failures: 1,
+            error: || CoreError::DataError(safe_nd::Error::NoSuchData),
+            sends: 0,
+        };
+
+        let res = with_retry(&mut query, 2, Duration::from_millis(1), |query| {
+            query.send().boxed()
+        })
+        .await;
+
+        match res {
+            Err(CoreError::DataError(safe_nd::Error::NoSuchData)) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+        assert_eq!(query.sends, 1);
+
+        Ok(())
+    }
 
     #[tokio::test]
     #[cfg(feature = "simulated-payouts")]
@@ -373,4 +1377,104 @@ mod tests {
     pub async fn client_creation_for_existing_sk() -> Result<(), CoreError> {
         exported_tests::client_creation_for_existing_sk().await
     }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_public_key_sync_matches_async() -> Result<(), CoreError> {
+        exported_tests::client_public_key_sync_matches_async().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_can_sign_and_verify_data() -> Result<(), CoreError> {
+        exported_tests::client_can_sign_and_verify_data().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_can_rotate_its_key() -> Result<(), CoreError> {
+        exported_tests::client_can_rotate_its_key().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_reports_balance_moved_when_key_rotation_fails() -> Result<(), CoreError> {
+        exported_tests::client_reports_balance_moved_when_key_rotation_fails().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_creation_with_bootstrap_contacts() -> Result<(), CoreError> {
+        exported_tests::client_creation_with_bootstrap_contacts().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn read_only_client_can_read_but_not_write() -> Result<(), CoreError> {
+        exported_tests::read_only_client_can_read_but_not_write().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_cannot_be_used_after_disconnect() -> Result<(), CoreError> {
+        exported_tests::client_cannot_be_used_after_disconnect().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_emits_spans_for_writes_and_transfers() -> Result<(), CoreError> {
+        exported_tests::client_emits_spans_for_writes_and_transfers().await
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    pub async fn client_reports_its_connection_status() -> Result<(), CoreError> {
+        exported_tests::client_reports_its_connection_status().await
+    }
+
+    #[tokio::test]
+    async fn client_requests_time_out_if_network_never_replies() -> Result<(), CoreError> {
+        use super::{Client, ClientConfig};
+        use crate::utils::test_utils::UnresponsiveTransport;
+        use crate::PaymentFailureKind;
+        use safe_nd::{Money, SequenceAddress};
+        use std::{str::FromStr, sync::Arc};
+        use xor_name::XorName;
+
+        let config = ClientConfig {
+            default_request_timeout: Some(Duration::from_millis(50)),
+            query_retries: 0,
+            ..Default::default()
+        };
+        let transport = Arc::new(UnresponsiveTransport::default());
+        let mut client = Client::with_transport(None, transport, config).await?;
+        let owner = client.public_key().await;
+        let address = SequenceAddress::Private {
+            name: XorName::random(),
+            tag: 15000,
+        };
+
+        let assert_timed_out = |res: Result<(), CoreError>| match res {
+            Err(CoreError::RequestTimeout) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        };
+        assert_timed_out(client.get_balance().await.map(|_| ()));
+        assert_timed_out(client.get_history().await);
+        assert_timed_out(client.get_sequence(address).await.map(|_| ()));
+        assert_timed_out(client.append_to_sequence(address, b"VALUE".to_vec()).await);
+        assert_timed_out(client.send_money(owner, Money::from_str("1")?).await);
+
+        // Paying for a write times out getting our transfer history, before any validation
+        match client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await
+        {
+            Err(CoreError::PaymentFailed {
+                kind: PaymentFailureKind::Network,
+            }) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        Ok(())
+    }
 }
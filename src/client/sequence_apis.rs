@@ -6,19 +6,124 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::errors::CoreError;
+use crate::client::is_transient;
+use crate::errors::{CoreError, PaymentFailureKind};
+use crate::utils::{symmetric_decrypt, symmetric_encrypt, SymEncKey};
 use crate::Client;
-use log::trace;
+use bincode::{deserialize, serialize};
+use futures::{
+    channel::oneshot,
+    future::Future,
+    stream::{self, Stream, StreamExt},
+};
+use log::{info, trace, warn};
+use safe_nd::Error as SndError;
 use safe_nd::{
     Cmd, DataCmd, DataQuery, DebitAgreementProof, PublicKey, Query, QueryResponse, Sequence,
     SequenceAction, SequenceAddress, SequenceEntries, SequenceEntry, SequenceIndex, SequenceOwner,
-    SequencePrivUserPermissions, SequencePrivatePermissions, SequencePubUserPermissions,
-    SequencePublicPermissions, SequenceRead, SequenceUser, SequenceUserPermissions, SequenceWrite,
-    SequenceWriteOp,
+    SequencePermissions, SequencePrivUserPermissions, SequencePrivatePermissions,
+    SequencePubUserPermissions, SequencePublicPermissions, SequenceRead, SequenceUser,
+    SequenceUserPermissions, SequenceWrite, SequenceWriteOp,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex as StdMutex, PoisonError},
+    time::Duration,
 };
-use std::collections::BTreeMap;
+use tiny_keccak::sha3_256;
+use tokio::time;
+use tracing::{info_span, Instrument};
 use xor_name::XorName;
 
+/// Maximum number of attempts at appending to a Sequence when access is denied.
+const MAX_APPEND_ATTEMPTS: usize = 2;
+
+/// Number of entries fetched at a time when streaming the entries of a Sequence.
+pub const SEQUENCE_ENTRY_STREAM_PAGE_SIZE: u64 = 100;
+
+/// Maximum number of Sequences fetched at once when prefetching them.
+const MAX_CONCURRENT_SEQUENCE_FETCHES: usize = 8;
+
+/// Interval at which the network is queried while waiting for an entry to be confirmed.
+const SEQUENCE_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+// Wait for the operation, failing with `CoreError::RequestTimeout` once the deadline elapses.
+async fn with_deadline<T, F>(deadline: Duration, operation: F) -> Result<T, CoreError>
+where
+    F: Future<Output = Result<T, CoreError>>,
+{
+    match time::timeout(deadline, operation).await {
+        Ok(res) => res,
+        Err(_) => Err(CoreError::RequestTimeout),
+    }
+}
+
+// A fetch of a Sequence from the network which other readers may be waiting on. Once dropped
+// the Sequence is no longer in flight, and readers still waiting fetch it themselves.
+struct InFlightSequenceFetch {
+    fetches: Arc<StdMutex<HashMap<SequenceAddress, Vec<oneshot::Sender<Sequence>>>>>,
+    address: SequenceAddress,
+    finished: bool,
+}
+
+impl InFlightSequenceFetch {
+    // Stop the fetch, returning the readers waiting on it
+    fn finish(mut self) -> Vec<oneshot::Sender<Sequence>> {
+        self.finished = true;
+        self.remove()
+    }
+
+    fn remove(&self) -> Vec<oneshot::Sender<Sequence>> {
+        self.fetches
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.address)
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for InFlightSequenceFetch {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.remove();
+        }
+    }
+}
+
+// Resolve a range of entries against the number of entries of a Sequence, into the
+// indices from the start of its first entry and of the entry following its last one.
+fn resolve_sequence_range(
+    range: (SequenceIndex, SequenceIndex),
+    entries_index: u64,
+) -> Result<(u64, u64), CoreError> {
+    let invalid_range = |reason: String| CoreError::InvalidRange { reason };
+
+    if entries_index == 0 {
+        return Err(invalid_range("the Sequence has no entries".to_string()));
+    }
+
+    let resolve = |index: SequenceIndex| match index {
+        SequenceIndex::FromStart(index) if index <= entries_index => Ok(index),
+        SequenceIndex::FromEnd(offset) if offset <= entries_index => Ok(entries_index - offset),
+        index => Err(invalid_range(format!(
+            "{:?} is out of bounds of the Sequence's {} entries",
+            index, entries_index
+        ))),
+    };
+    let start = resolve(range.0)?;
+    let end = resolve(range.1)?;
+
+    if start > end {
+        return Err(invalid_range(format!(
+            "start {} is after end {}",
+            start, end
+        )));
+    }
+
+    Ok((start, end))
+}
+
 fn wrap_seq_read(read: SequenceRead) -> Query {
     Query::Data(DataQuery::Sequence(read))
 }
@@ -30,6 +135,467 @@ fn wrap_seq_write(write: SequenceWrite, payment: DebitAgreementProof) -> Cmd {
     }
 }
 
+// Scope of the Sequence at the address, which encodes it.
+fn scope_of(address: SequenceAddress) -> Scope {
+    if address.is_pub() {
+        Scope::Public
+    } else {
+        Scope::Private
+    }
+}
+
+// Fail with `WrongSequenceScope` unless the address is of the expected scope.
+fn check_sequence_scope(address: SequenceAddress, expected: Scope) -> Result<(), CoreError> {
+    if scope_of(address) != expected {
+        return Err(CoreError::WrongSequenceScope { expected });
+    }
+    Ok(())
+}
+
+// Turn an access denied by a Sequence into the error for the action attempted.
+fn map_sequence_access_error(action: SequenceAction) -> impl FnOnce(SndError) -> CoreError {
+    move |error| match error {
+        SndError::AccessDenied => CoreError::SequenceAccessDenied { action },
+        error => CoreError::from(error),
+    }
+}
+
+/// Scope of a Sequence, either public or private.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Scope {
+    /// Public Sequence, which can't be deleted.
+    Public,
+    /// Private Sequence.
+    Private,
+}
+
+/// Differences between the local CRDT replica of a Sequence and the network's replicas,
+/// as reported by `Client::diff_sequence`.
+///
+/// Entries are listed along with their index in the replica they're found in.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SequenceDiff {
+    /// Entries in the local replica which the network doesn't have.
+    pub local_only_entries: Vec<(u64, SequenceEntry)>,
+    /// Entries on the network which the local replica doesn't have.
+    pub remote_only_entries: Vec<(u64, SequenceEntry)>,
+    /// Owners in the local replica which the network doesn't have.
+    pub local_only_owners: Vec<SequenceOwner>,
+    /// Owners on the network which the local replica doesn't have.
+    pub remote_only_owners: Vec<SequenceOwner>,
+    /// Permissions in the local replica which the network doesn't have.
+    pub local_only_permissions: Vec<SequencePermissions>,
+    /// Permissions on the network which the local replica doesn't have.
+    pub remote_only_permissions: Vec<SequencePermissions>,
+}
+
+impl SequenceDiff {
+    /// Check whether both replicas hold the same entries, owners and permissions.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+// Entries, owners and permissions of a Sequence replica, to compare it with another one.
+#[derive(Default)]
+struct SequenceReplicaState {
+    entries: Vec<(u64, SequenceEntry)>,
+    owners: Vec<SequenceOwner>,
+    permissions: Vec<SequencePermissions>,
+}
+
+impl SequenceReplicaState {
+    fn of(sequence: &Sequence) -> Result<Self, CoreError> {
+        let entries_index = sequence.entries_index();
+        let entries = sequence
+            .in_range(
+                SequenceIndex::FromStart(0),
+                SequenceIndex::FromStart(entries_index),
+            )
+            .unwrap_or_default();
+        let first_index = entries_index - entries.len() as u64;
+
+        Ok(Self {
+            entries: (first_index..).zip(entries).collect(),
+            owners: sequence_owners(sequence)?,
+            permissions: sequence_permissions(sequence)?,
+        })
+    }
+}
+
+// The items of `items` which aren't in `others`.
+fn only_in<T: Clone, U: PartialEq>(items: &[T], others: &[T], key: impl Fn(&T) -> &U) -> Vec<T> {
+    items
+        .iter()
+        .filter(|item| !others.iter().any(|other| key(other) == key(item)))
+        .cloned()
+        .collect()
+}
+
+// All the owners a Sequence has had, oldest first.
+fn sequence_owners(sequence: &Sequence) -> Result<Vec<SequenceOwner>, CoreError> {
+    (0..sequence.owners_index())
+        .map(|index| {
+            sequence.owner(index).copied().ok_or_else(|| {
+                CoreError::from(format!(
+                    "Unexpectedly failed to obtain owner at index {} of Sequence",
+                    index
+                ))
+            })
+        })
+        .collect()
+}
+
+// All the permissions a Sequence has had, oldest first.
+fn sequence_permissions(sequence: &Sequence) -> Result<Vec<SequencePermissions>, CoreError> {
+    (0..sequence.permissions_index())
+        .map(|index| -> Result<SequencePermissions, CoreError> {
+            let perms: SequencePermissions = if sequence.is_pub() {
+                sequence.pub_permissions(index)?.clone().into()
+            } else {
+                sequence.private_permissions(index)?.clone().into()
+            };
+            Ok(perms)
+        })
+        .collect()
+}
+
+/// Derive a deterministic Sequence address from a seed, by hashing it into the Sequence name.
+///
+/// The same seed, tag and scope always give the same address, which is the address the
+/// store APIs compute for the derived name and tag.
+///
+/// # Examples
+///
+/// ```
+/// use safe_core::client::sequence_apis::{sequence_address_from_seed, Scope};
+///
+/// let address = sequence_address_from_seed(b"my app settings", 10, Scope::Private);
+/// assert_eq!(address, sequence_address_from_seed(b"my app settings", 10, Scope::Private));
+/// assert!(address.is_private());
+/// ```
+pub fn sequence_address_from_seed(seed: &[u8], tag: u64, scope: Scope) -> SequenceAddress {
+    let name = XorName(sha3_256(seed));
+    match scope {
+        Scope::Public => SequenceAddress::Public { name, tag },
+        Scope::Private => SequenceAddress::Private { name, tag },
+    }
+}
+
+/// Public permissions granting the same `perms` to each of the keys, to store a public Sequence.
+///
+/// # Examples
+///
+/// ```
+/// use safe_core::client::sequence_apis::{anyone_read_only, pub_perms_for};
+/// use safe_nd::{PublicKey, SequencePubUserPermissions, SequenceUser};
+///
+/// let key = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+/// let mut perms = pub_perms_for(&[key], SequencePubUserPermissions::new(true, false));
+/// perms.extend(anyone_read_only());
+/// assert_eq!(perms.len(), 2);
+/// assert!(perms.contains_key(&SequenceUser::Key(key)));
+/// ```
+pub fn pub_perms_for(
+    keys: &[PublicKey],
+    perms: SequencePubUserPermissions,
+) -> BTreeMap<SequenceUser, SequencePubUserPermissions> {
+    keys.iter()
+        .map(|key| (SequenceUser::Key(*key), perms))
+        .collect()
+}
+
+/// Private permissions granting the same `perms` to each of the keys, to store a private Sequence.
+pub fn private_perms_for(
+    keys: &[PublicKey],
+    perms: SequencePrivUserPermissions,
+) -> BTreeMap<PublicKey, SequencePrivUserPermissions> {
+    keys.iter().map(|key| (*key, perms)).collect()
+}
+
+/// Public permissions letting anyone read a public Sequence, but neither append to it nor
+/// manage its permissions.
+pub fn anyone_read_only() -> BTreeMap<SequenceUser, SequencePubUserPermissions> {
+    let mut perms = BTreeMap::new();
+    let _ = perms.insert(
+        SequenceUser::Anyone,
+        SequencePubUserPermissions::new(false, false),
+    );
+    perms
+}
+
+/// Permissions granted to a user by a `SequenceBuilder`, of the kind matching the scope
+/// of the Sequence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SequenceGrant {
+    /// Permissions on a Public Sequence.
+    Public(SequencePubUserPermissions),
+    /// Permissions on a Private Sequence.
+    Private(SequencePrivUserPermissions),
+}
+
+impl From<SequencePubUserPermissions> for SequenceGrant {
+    fn from(perms: SequencePubUserPermissions) -> Self {
+        Self::Public(perms)
+    }
+}
+
+impl From<SequencePrivUserPermissions> for SequenceGrant {
+    fn from(perms: SequencePrivUserPermissions) -> Self {
+        Self::Private(perms)
+    }
+}
+
+/// Builder of a new Sequence, stored on the network with `SequenceBuilder::store`.
+///
+/// The Sequence is private unless `public` is called, and owned by the client storing it
+/// unless another `owner` is set. A name and a tag must be set.
+///
+/// # Examples
+/// ```no_run
+/// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+/// use safe_core::Client;
+/// use safe_core::client::sequence_apis::SequenceBuilder;
+/// use safe_nd::{Money, SequencePubUserPermissions, SequenceUser};
+/// use xor_name::XorName;
+/// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+/// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+/// let mut client = Client::new(Some(secret_key)).await?;
+/// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+/// let address = SequenceBuilder::new()
+///     .public()
+///     .name(XorName::random())
+///     .tag(10)
+///     .grant(SequenceUser::Anyone, SequencePubUserPermissions::new(true, false))
+///     .store(&mut client)
+///     .await?;
+/// assert!(address.is_pub());
+/// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequenceBuilder {
+    scope: Scope,
+    name: Option<XorName>,
+    tag: Option<u64>,
+    owner: Option<PublicKey>,
+    grants: Vec<(SequenceUser, SequenceGrant)>,
+}
+
+impl Default for SequenceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SequenceBuilder {
+    /// Start building a private Sequence, without any permissions granted yet.
+    pub fn new() -> Self {
+        Self {
+            scope: Scope::Private,
+            name: None,
+            tag: None,
+            owner: None,
+            grants: Vec::new(),
+        }
+    }
+
+    /// Make the Sequence public.
+    pub fn public(mut self) -> Self {
+        self.scope = Scope::Public;
+        self
+    }
+
+    /// Make the Sequence private.
+    pub fn private(mut self) -> Self {
+        self.scope = Scope::Private;
+        self
+    }
+
+    /// Set the name of the Sequence.
+    pub fn name(mut self, name: XorName) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the type tag of the Sequence.
+    pub fn tag(mut self, tag: u64) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Set the owner of the Sequence, instead of the client storing it.
+    pub fn owner(mut self, owner: PublicKey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Grant permissions to a user, replacing any granted to them before. Public permissions
+    /// can only be granted on a public Sequence, and private ones to keys on a private Sequence.
+    pub fn grant(mut self, user: SequenceUser, perms: impl Into<SequenceGrant>) -> Self {
+        self.grants.retain(|(granted, _)| *granted != user);
+        self.grants.push((user, perms.into()));
+        self
+    }
+
+    /// Store the Sequence on the network, paid for by the client, returning its address.
+    ///
+    /// Fails without storing anything should the name or tag be missing, or any permissions
+    /// granted not match the scope of the Sequence.
+    pub async fn store(self, client: &mut Client) -> Result<SequenceAddress, CoreError> {
+        let name = self
+            .name
+            .ok_or_else(|| CoreError::from("No name set for the Sequence"))?;
+        let tag = self
+            .tag
+            .ok_or_else(|| CoreError::from("No tag set for the Sequence"))?;
+        let owner = match self.owner {
+            Some(owner) => owner,
+            None => client.public_key().await,
+        };
+
+        match self.scope {
+            Scope::Public => {
+                let mut perms = BTreeMap::new();
+                for (user, grant) in self.grants {
+                    match grant {
+                        SequenceGrant::Public(user_perms) => {
+                            let _ = perms.insert(user, user_perms);
+                        }
+                        SequenceGrant::Private(_) => {
+                            return Err(CoreError::from(format!(
+                                "Private permissions granted to {:?} on a public Sequence",
+                                user
+                            )))
+                        }
+                    }
+                }
+                client
+                    .store_public_sequence(None, name, tag, owner, perms)
+                    .await
+            }
+            Scope::Private => {
+                let mut perms = BTreeMap::new();
+                for (user, grant) in self.grants {
+                    match (user, grant) {
+                        (SequenceUser::Key(key), SequenceGrant::Private(user_perms)) => {
+                            let _ = perms.insert(key, user_perms);
+                        }
+                        (user, _) => {
+                            return Err(CoreError::from(format!(
+                                "Only private permissions to keys can be granted on a private \
+                                 Sequence, not to {:?}",
+                                user
+                            )))
+                        }
+                    }
+                }
+                client
+                    .store_private_sequence(None, name, tag, owner, perms)
+                    .await
+            }
+        }
+    }
+}
+
+// Operation of a `SequenceTransaction`, before it's applied to the Sequence
+#[derive(Clone, Debug)]
+enum SequenceTransactionOp {
+    Append(SequenceEntry),
+    SetOwner(PublicKey),
+    SetPubPermissions(BTreeMap<SequenceUser, SequencePubUserPermissions>),
+    SetPrivatePermissions(BTreeMap<PublicKey, SequencePrivUserPermissions>),
+}
+
+// Operation of a `SequenceTransaction` applied to the local replica, to be sent to the network
+enum SequenceTransactionWrite {
+    Append(SequenceWriteOp<SequenceEntry>),
+    SetOwner(SequenceWriteOp<SequenceOwner>),
+    SetPubPermissions(SequenceWriteOp<SequencePublicPermissions>),
+    SetPrivatePermissions(SequenceWriteOp<SequencePrivatePermissions>),
+}
+
+/// Operations on a Sequence to be made together, in order, with
+/// `Client::commit_sequence_transaction`.
+///
+/// # Examples
+/// ```no_run
+/// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+/// use safe_core::Client;
+/// use safe_core::client::sequence_apis::SequenceTransaction;
+/// use safe_nd::{Money, PublicKey, SequencePrivUserPermissions};
+/// use std::collections::BTreeMap;
+/// use xor_name::XorName;
+/// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+/// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+/// let mut client = Client::new(Some(secret_key)).await?;
+/// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+/// let owner = client.public_key().await;
+/// let collaborator = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+/// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+///
+/// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+/// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+/// let _ = perms.insert(collaborator, SequencePrivUserPermissions::new(true, true, false));
+/// let txn = SequenceTransaction::new()
+///     .append(b"Welcome aboard".to_vec())
+///     .set_private_permissions(perms);
+/// client.commit_sequence_transaction(address, txn).await?;
+/// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SequenceTransaction {
+    ops: Vec<SequenceTransactionOp>,
+}
+
+impl SequenceTransaction {
+    /// Start a transaction without any operation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an entry.
+    pub fn append(mut self, entry: SequenceEntry) -> Self {
+        self.ops.push(SequenceTransactionOp::Append(entry));
+        self
+    }
+
+    /// Set a new owner.
+    pub fn set_owner(mut self, owner: PublicKey) -> Self {
+        self.ops.push(SequenceTransactionOp::SetOwner(owner));
+        self
+    }
+
+    /// Replace the permissions of a public Sequence.
+    pub fn set_pub_permissions(
+        mut self,
+        permissions: BTreeMap<SequenceUser, SequencePubUserPermissions>,
+    ) -> Self {
+        self.ops
+            .push(SequenceTransactionOp::SetPubPermissions(permissions));
+        self
+    }
+
+    /// Replace the permissions of a private Sequence.
+    pub fn set_private_permissions(
+        mut self,
+        permissions: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+    ) -> Self {
+        self.ops
+            .push(SequenceTransactionOp::SetPrivatePermissions(permissions));
+        self
+    }
+
+    /// Number of operations in the transaction, each paid for as a single write.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Whether the transaction has no operation.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
 impl Client {
     //----------------------
     // Write Operations
@@ -43,6 +609,11 @@ impl Client {
     /// A tag must be supplied.
     /// A xorname must be supplied, this can be random or deterministic as per your apps needs.
     ///
+    /// Only the users in `permissions` are granted access, as set for each of them, and there's
+    /// no permission for anyone else: with an empty map the owner is the only user who can read,
+    /// append to or administer the Sequence. `can_user_read` and `can_user_append` tell what a
+    /// given user is allowed to do.
+    ///
     /// # Examples
     ///
     /// Store data
@@ -94,9 +665,12 @@ impl Client {
         }
 
         self.pay_and_write_sequence_to_network(data.clone()).await?;
+        self.record_created_sequence(address);
 
-        // Store in local Sequence CRDT replica
-        let _ = self.sequence_cache.lock().await.put(*data.address(), data);
+        // Store in local Sequence CRDT replica, unless we were asked not to
+        if self.cache_on_write {
+            self.cache_sequence(data).await;
+        }
 
         Ok(address)
     }
@@ -159,20 +733,24 @@ impl Client {
         }
 
         self.pay_and_write_sequence_to_network(data.clone()).await?;
+        self.record_created_sequence(address);
 
-        // Store in local Sequence CRDT replica
-        let _ = self.sequence_cache.lock().await.put(*data.address(), data);
+        // Store in local Sequence CRDT replica, unless we were asked not to
+        if self.cache_on_write {
+            self.cache_sequence(data).await;
+        }
 
         Ok(address)
     }
 
-    /// Delete sequence
+    /// Create Private Sequence Data on to the Network, already holding the given entries
     ///
-    /// You're only able to delete a PrivateSequence. Public data can no be removed from the network.
+    /// The Sequence is stored with all its entries in a single write, so this costs a single payment.
+    /// The address is returned along with the index of the Sequence entries, ie. the number of entries.
     ///
     /// # Examples
     ///
-    /// Delete data
+    /// Store data with initial entries
     ///
     /// ```no_run
     /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
@@ -195,37 +773,47 @@ impl Client {
     ///    SequencePrivUserPermissions::new(true, true, true),
     /// );
     ///
-    /// // The returned address can then be used to `append` data to.
-    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
-    ///
-    /// client.delete_sequence(address).await?;
+    /// let entries = vec![b"First Entry".to_vec(), b"Second Entry".to_vec()];
+    /// let (_address, entries_index) = client
+    ///     .store_private_sequence_with_entries(entries, name, tag, owner, perms)
+    ///     .await?;
+    /// assert_eq!(entries_index, 2);
     /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
     /// ```
-    pub async fn delete_sequence(&mut self, address: SequenceAddress) -> Result<(), CoreError> {
-        // --------------------------
-        // Payment for PUT
-        // --------------------------
-        let payment_proof = self.create_write_payment_proof().await?;
+    pub async fn store_private_sequence_with_entries(
+        &mut self,
+        entries: Vec<SequenceEntry>,
+        name: XorName,
+        tag: u64,
+        owner: PublicKey,
+        permissions: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+    ) -> Result<(SequenceAddress, u64), CoreError> {
+        trace!(
+            "Store Private Sequence Data {:?} with {} entries",
+            name,
+            entries.len()
+        );
+        let address = self
+            .store_private_sequence(Some(entries), name, tag, owner, permissions)
+            .await?;
 
-        //---------------------------------
-        // The _actual_ message
-        //---------------------------------
-        let msg_contents = wrap_seq_write(SequenceWrite::Delete(address), payment_proof.clone());
-        let message = Self::create_cmd_message(msg_contents);
-        let _ = self.connection_manager.send_cmd(&message).await?;
+        // Served from our local Sequence CRDT replica
+        let entries_index = self.get_sequence(address).await?.entries_index();
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok((address, entries_index))
     }
 
-    /// Append to Sequence
+    /// Store a Private Sequence as with `store_private_sequence`, with each of its initial entries
+    /// symmetrically encrypted with the given key, so the nodes never see them in plain text.
     ///
-    /// Public or private isn't important for append. You can append to either (though the data you append will be Public or Private).
+    /// The same key must then be given to `append_to_encrypted_sequence` and
+    /// `get_encrypted_sequence_range`. It isn't stored anywhere by the client.
     ///
     /// # Examples
     /// ```no_run
     /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
-    /// use safe_core::Client;
-    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use safe_core::{utils, Client};
+    /// use safe_nd::{PublicKey, Money, SequenceIndex, SequencePrivUserPermissions};
     /// use std::collections::BTreeMap;
     /// use xor_name::XorName;
     /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
@@ -236,158 +824,241 @@ impl Client {
     /// let tag = 10;
     /// let owner = client.public_key().await;
     /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
     ///
-    /// // Set the access permissions
-    /// let _ = perms.insert(
-    ///    owner,
-    ///    SequencePrivUserPermissions::new(true, true, true),
-    /// );
-    ///
-    /// // The returned address can then be used to `append` data to.
-    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// let key = utils::generate_sym_enc_key();
+    /// let address = client
+    ///     .store_encrypted_private_sequence(None, name, tag, owner, perms, &key)
+    ///     .await?;
+    /// client.append_to_encrypted_sequence(address, b"Confidential".to_vec(), &key).await?;
     ///
-    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    /// let entries = client
+    ///     .get_encrypted_sequence_range(address, (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)), &key)
+    ///     .await?;
+    /// assert_eq!(entries[0], b"Confidential".to_vec());
     /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
     /// ```
-    pub async fn append_to_sequence(
+    pub async fn store_encrypted_private_sequence(
         &mut self,
-        address: SequenceAddress,
-        entry: SequenceEntry,
-    ) -> Result<(), CoreError> {
-        // First we fetch it so we can get the causality info,
-        // either from local CRDT replica or from the network if not found
-        let mut sequence = self.get_sequence(address).await?;
-
-        // We do a permissions check just to make sure it won't fail when the operation
-        // is broadcasted to the network, assuming our replica is in sync and up to date
-        // with the permissions and ownership information compared with the replicas on the network.
-        sequence.check_permission(SequenceAction::Append, self.public_id().await.public_key())?;
-
-        // We can now append the entry to the Sequence
-        let op = sequence.append(entry);
-
-        // Update the local Sequence CRDT replica
-        let _ = self
-            .sequence_cache
-            .lock()
+        sequence: Option<SequenceEntries>,
+        name: XorName,
+        tag: u64,
+        owner: PublicKey,
+        permissions: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+        encryption_key: &SymEncKey,
+    ) -> Result<SequenceAddress, CoreError> {
+        trace!("Store encrypted Private Sequence Data {:?}", name);
+        let sequence = match sequence {
+            Some(entries) => Some(
+                entries
+                    .iter()
+                    .map(|entry| symmetric_encrypt(entry, encryption_key, None))
+                    .collect::<Result<SequenceEntries, CoreError>>()?,
+            ),
+            None => None,
+        };
+
+        self.store_private_sequence(sequence, name, tag, owner, permissions)
             .await
-            .put(*sequence.address(), sequence.clone());
-        // Finally we can send the mutation to the network's replicas
-        self.pay_and_write_append_to_sequence_to_network(op).await
-    }
-
-    /// Append data to a sequenced data object
-    /// Wraps msg_contents for payment validation and mutation
-    async fn pay_and_write_append_to_sequence_to_network(
-        &mut self,
-        op: SequenceWriteOp<Vec<u8>>,
-    ) -> Result<(), CoreError> {
-        // --------------------------
-        // Payment for PUT
-        // --------------------------
-        let payment_proof = self.create_write_payment_proof().await?;
-
-        //---------------------------------
-        // The _actual_ message
-        //---------------------------------
-        let msg_contents = wrap_seq_write(SequenceWrite::Edit(op), payment_proof.clone());
-        let message = Self::create_cmd_message(msg_contents);
-        let _ = self.connection_manager.send_cmd(&message).await?;
-
-        self.apply_write_payment_to_local_actor(payment_proof).await
     }
 
-    /// Store a new public sequenced data object
-    /// Wraps msg_contents for payment validation and mutation
-    pub(crate) async fn pay_and_write_sequence_to_network(
+    /// Store a new Private Sequence owned by this client, which is the only user it grants
+    /// permissions to, and all of them: read, append and admin.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    ///
+    /// let address = client.store_private_sequence_owner_only(None, name, tag).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn store_private_sequence_owner_only(
         &mut self,
-        data: Sequence,
-    ) -> Result<(), CoreError> {
-        // --------------------------
-        // Payment for PUT
-        // --------------------------
-        let payment_proof = self.create_write_payment_proof().await?;
-
-        //---------------------------------
-        // The _actual_ message
-        //---------------------------------
-        let msg_contents = wrap_seq_write(SequenceWrite::New(data), payment_proof.clone());
-        let message = Self::create_cmd_message(msg_contents);
-        let _ = self.connection_manager.send_cmd(&message).await?;
+        sequence: Option<SequenceEntries>,
+        name: XorName,
+        tag: u64,
+    ) -> Result<SequenceAddress, CoreError> {
+        trace!("Store owner only Private Sequence Data {:?}", name);
+        let owner = self.public_key().await;
+        let mut permissions = BTreeMap::new();
+        let _ = permissions.insert(owner, SequencePrivUserPermissions::new(true, true, true));
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        self.store_private_sequence(sequence, name, tag, owner, permissions)
+            .await
     }
 
-    //----------------------
-    // Get Sequence
-    //---------------------
-
-    /// Get Sequence Data from the Network
+    /// Store a new Private Sequence as with `store_private_sequence`, unless there's already
+    /// Sequence Data at its address.
+    ///
+    /// The network is queried first, and should the address hold a Sequence this fails with
+    /// `CoreError::DataError(safe_nd::Error::DataExists)` without paying for the write, which
+    /// the network would reject anyway. This lets provisioning be retried without wasting fees.
     ///
     /// # Examples
     /// ```no_run
     /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
     /// use safe_core::Client;
-    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use safe_nd::{Error as SndError, Money};
     /// use std::collections::BTreeMap;
     /// use xor_name::XorName;
     /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
-    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
     /// let mut client = Client::new(Some(secret_key)).await?;
     /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
     /// let name = XorName::random();
-    /// let tag = 10;
     /// let owner = client.public_key().await;
-    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
     ///
-    /// // Set the access permissions
-    /// let _ = perms.insert(
-    ///    owner,
-    ///    SequencePrivUserPermissions::new(true, true, true),
-    /// );
+    /// match client.store_sequence_if_absent(None, name, 10, owner, BTreeMap::new()).await {
+    ///     Ok(_address) => println!("Sequence provisioned"),
+    ///     Err(CoreError::DataError(SndError::DataExists)) => println!("Already provisioned"),
+    ///     Err(error) => return Err(error),
+    /// }
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn store_sequence_if_absent(
+        &mut self,
+        sequence: Option<SequenceEntries>,
+        name: XorName,
+        tag: u64,
+        owner: PublicKey,
+        permissions: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+    ) -> Result<SequenceAddress, CoreError> {
+        trace!("Store Private Sequence Data {:?} if absent", name);
+        let address = SequenceAddress::Private { name, tag };
+        if self.sequence_exists(address).await? {
+            return Err(CoreError::DataError(SndError::DataExists));
+        }
+
+        self.store_private_sequence(sequence, name, tag, owner, permissions)
+            .await
+    }
+
+    /// Store a new Public Sequence which anyone can read, but only this client can append to.
     ///
-    /// // The returned address can then be used to `append` data to.
-    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// `SequenceUser::Anyone` is given no append permission, while this client's public key
+    /// is set as the owner and given the append permission.
     ///
-    /// let _data = client.get_sequence(address).await?;
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
     ///
+    /// let address = client.store_broadcast_sequence(name, tag).await?;
+    /// client.append_to_sequence(address, b"Announcement".to_vec()).await?;
     /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
     /// ```
-    pub async fn get_sequence(&mut self, address: SequenceAddress) -> Result<Sequence, CoreError> {
-        trace!("Get Sequence Data at {:?}", address.name());
-        // First try to fetch it from local CRDT replica
-        // TODO: implement some logic to refresh data from the network if local replica
-        // is too old, to mitigate the risk of successfully apply mutations locally but which
-        // can fail on other replicas, e.g. due to being out of sync with permissions/owner
-        if let Some(sequence) = self.sequence_cache.lock().await.get(&address) {
-            trace!("Sequence found in local CRDT replica");
-            return Ok(sequence.clone());
-        }
+    pub async fn store_broadcast_sequence(
+        &mut self,
+        name: XorName,
+        tag: u64,
+    ) -> Result<SequenceAddress, CoreError> {
+        trace!("Store broadcast Sequence Data {:?}", name);
+        let owner = self.public_key().await;
 
-        trace!("Sequence not found in local CRDT replica");
-        // Let's fetch it from the network then
-        let sequence = match self
-            .send_query(wrap_seq_read(SequenceRead::Get(address)))
-            .await?
-        {
-            QueryResponse::GetSequence(res) => res.map_err(CoreError::from),
-            _ => Err(CoreError::ReceivedUnexpectedEvent),
-        }?;
+        let mut permissions = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        // Public data can be read by anyone, so there's only append to deny
+        let _ = permissions.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(false, false),
+        );
+        let _ = permissions.insert(
+            SequenceUser::Key(owner),
+            SequencePubUserPermissions::new(true, false),
+        );
 
-        trace!("Store Sequence in local CRDT replica");
-        // Store in local Sequence CRDT replica
-        let _ = self
-            .sequence_cache
-            .lock()
+        self.store_public_sequence(None, name, tag, owner, permissions)
             .await
-            .put(*sequence.address(), sequence.clone());
+    }
 
-        Ok(sequence)
+    /// Copy all the entries of a Sequence to a new Sequence, of the same scope, at the given
+    /// name and tag.
+    ///
+    /// The new Sequence has the current permissions of the source one, with this client as its
+    /// owner, and its entries are in the same order. Storing it costs a single write payment.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, XorName::random(), tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    ///
+    /// let new_address = client.copy_sequence(address, XorName::random(), tag).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn copy_sequence(
+        &mut self,
+        src: SequenceAddress,
+        dst_name: XorName,
+        dst_tag: u64,
+    ) -> Result<SequenceAddress, CoreError> {
+        trace!("Copy Sequence Data at {:?} to {:?}", src.name(), dst_name);
+
+        let sequence = self.get_sequence(src).await?;
+        let entries = if sequence.entries_index() == 0 {
+            SequenceEntries::new()
+        } else {
+            sequence
+                .in_range(SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0))
+                .ok_or_else(|| CoreError::from(safe_nd::Error::NoSuchEntry))?
+        };
+        let owner = self.public_key().await;
+
+        if src.is_pub() {
+            let permissions = self.get_sequence_pub_permissions(src).await?.permissions;
+            self.store_public_sequence(Some(entries), dst_name, dst_tag, owner, permissions)
+                .await
+        } else {
+            let permissions = self
+                .get_sequence_private_permissions(src)
+                .await?
+                .permissions;
+            self.store_private_sequence(Some(entries), dst_name, dst_tag, owner, permissions)
+                .await
+        }
     }
 
-    /// Get the last data entry from a Sequence Data.
+    /// Delete sequence
+    ///
+    /// You're only able to delete a PrivateSequence. Public data can no be removed from the network.
+    ///
+    /// Deleting a Public Sequence, or one which the local CRDT replica shows is owned by another
+    /// key, fails with `CoreError::OperationForbidden` before anything is paid or sent.
+    ///
+    /// Deleting costs a single write payment, see `get_write_price`.
     ///
     /// # Examples
+    ///
+    /// Delete data
+    ///
     /// ```no_run
     /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
     /// use safe_core::Client;
@@ -411,40 +1082,67 @@ impl Client {
     ///
     /// // The returned address can then be used to `append` data to.
     /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
-    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
-    /// client.append_to_sequence(address, b"Another New Entry Value".to_vec()).await?;
-    ///
-    /// // Now we can retrieve the alst entry in the sequence:
-    /// let (_position, last_entry) = client.get_sequence_last_entry(address).await?;
-    ///
-    /// assert_eq!(last_entry, b"Another New Entry Value".to_vec());
     ///
+    /// client.delete_sequence(address).await?;
     /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
     /// ```
-    #[allow(dead_code)]
-    pub async fn get_sequence_last_entry(
-        &mut self,
-        address: SequenceAddress,
-    ) -> Result<(u64, SequenceEntry), CoreError> {
-        trace!(
-            "Get latest entry from Sequence Data at {:?}",
-            address.name()
-        );
+    pub async fn delete_sequence(&mut self, address: SequenceAddress) -> Result<(), CoreError> {
+        if address.is_pub() {
+            warn!("Public Sequence at {:?} can't be deleted", address.name());
+            return Err(CoreError::OperationForbidden);
+        }
 
-        let sequence = self.get_sequence(address).await?;
-        match sequence.last_entry() {
-            Some(entry) => Ok((sequence.entries_index() - 1, entry.to_vec())),
-            None => Err(CoreError::from(safe_nd::Error::NoSuchEntry)),
+        // Only the owner can delete it, which the local replica may already tell
+        if let Some(sequence) = self.cached_sequence(address).await {
+            let owner = sequence.owner(sequence.owners_index() - 1);
+            if owner.map(|owner| owner.public_key) != Some(self.public_key_sync()) {
+                warn!("Sequence at {:?} is owned by another key", address.name());
+                return Err(CoreError::OperationForbidden);
+            }
         }
+
+        let write = SequenceWrite::Delete(address);
+        if self.skip_sequence_write_in_dry_run(&write)? {
+            self.invalidate_sequence_cache(address).await;
+            return Ok(());
+        }
+
+        // --------------------------
+        // Payment for PUT
+        // --------------------------
+        let payment_proof = self.create_write_payment_proof().await?;
+
+        //---------------------------------
+        // The _actual_ message
+        //---------------------------------
+        let msg_contents = wrap_seq_write(write, payment_proof);
+        let message = Self::create_cmd_message(msg_contents);
+        let _ = self.connection_manager.send_cmd(&message).await?;
+
+        self.forget_created_sequence(address);
+
+        // Drop the deleted Sequence from the local CRDT replica
+        self.invalidate_sequence_cache(address).await;
+
+        Ok(())
     }
 
-    /// Get a set of Entries for the requested range from a Sequence.
+    /// Append to Sequence
+    ///
+    /// Public or private isn't important for append. You can append to either (though the data you append will be Public or Private).
+    ///
+    /// Each append costs a single write payment, see `get_write_price`. Entries larger than
+    /// `ClientConfig::max_entry_size` fail with `CoreError::EntryTooLarge`, without paying.
+    ///
+    /// An append denied by the permissions of the locally cached Sequence is retried once with
+    /// the Sequence fetched from the network. Commands are sent without awaiting the Elders'
+    /// response, so an append the network's replicas deny isn't reported here, nor retried.
     ///
     /// # Examples
     /// ```no_run
     /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
     /// use safe_core::Client;
-    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions, SequenceIndex};
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
     /// use std::collections::BTreeMap;
     /// use xor_name::XorName;
     /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
@@ -464,40 +1162,137 @@ impl Client {
     ///
     /// // The returned address can then be used to `append` data to.
     /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
     /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
-    /// client.append_to_sequence(address, b"Another New Entry Value".to_vec()).await?;
-    /// client.append_to_sequence(address, b"Third Entry Value".to_vec()).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn append_to_sequence(
+        &mut self,
+        address: SequenceAddress,
+        entry: SequenceEntry,
+    ) -> Result<(), CoreError> {
+        let span = info_span!("append_to_sequence", address = ?address);
+        async {
+            let _ = self.append_to_sequence_indexed(address, entry).await?;
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Append to Sequence only if it has exactly `expected_index` entries, i.e. the entry
+    /// would be appended at `expected_index`, which allows optimistic concurrency control.
     ///
-    /// // Now we can retrieve the alst entry in the sequence:
-    /// let entries = client.get_sequence_range(address, (SequenceIndex::FromStart(1), SequenceIndex::FromEnd(0) )).await?;
+    /// The Sequence is fetched from the network first. Should it have another number of entries,
+    /// nothing is appended and it fails with `CoreError::ConcurrentModification`. Appends made
+    /// concurrently by other clients after the check can still be merged before this one.
     ///
-    /// assert_eq!(entries[0], b"Another New Entry Value".to_vec());
-    /// assert_eq!(entries[1], b"Third Entry Value".to_vec());
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
     ///
+    /// client.append_if_index(address, 0, b"First Entry".to_vec()).await?;
+    /// assert!(client.append_if_index(address, 0, b"Not First".to_vec()).await.is_err());
     /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
     /// ```
-    #[allow(dead_code)]
-    pub async fn get_sequence_range(
+    pub async fn append_if_index(
         &mut self,
         address: SequenceAddress,
-        range: (SequenceIndex, SequenceIndex),
-    ) -> Result<SequenceEntries, CoreError> {
-        trace!(
-            "Get range of entries from Sequence Data at {:?}",
-            address.name()
-        );
+        expected_index: u64,
+        entry: SequenceEntry,
+    ) -> Result<(), CoreError> {
+        let actual = self
+            .get_sequence_with_opts(address, true)
+            .await?
+            .entries_index();
+        if actual != expected_index {
+            return Err(CoreError::ConcurrentModification { actual });
+        }
 
-        let sequence = self.get_sequence(address).await?;
-        sequence
-            .in_range(range.0, range.1)
-            .ok_or_else(|| CoreError::from(safe_nd::Error::NoSuchEntry))
+        self.append_to_sequence(address, entry).await
     }
 
-    //----------------------
-    // Ownership
-    //---------------------
+    /// Append a value to a Sequence, as with `append_to_sequence`, serialised with bincode
+    /// into the entry's bytes. Read it back with `get_sequence_range_typed`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequenceIndex, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// client.append_typed(address, &(1u64, "first".to_string())).await?;
+    /// let entries: Vec<(u64, String)> = client
+    ///     .get_sequence_range_typed(address, (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)))
+    ///     .await?;
+    /// assert_eq!(entries, vec![(1, "first".to_string())]);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn append_typed<T: Serialize>(
+        &mut self,
+        address: SequenceAddress,
+        value: &T,
+    ) -> Result<(), CoreError> {
+        let entry = serialize(value)?;
+        self.append_to_sequence(address, entry).await
+    }
 
-    /// Update sequence data owners
+    /// Append a JSON value to a Sequence, as with `append_to_sequence`, encoded as UTF-8 JSON
+    /// so that tools not using bincode can read it. Read it back with `get_sequence_range_json`.
+    pub async fn append_json(
+        &mut self,
+        address: SequenceAddress,
+        value: &serde_json::Value,
+    ) -> Result<(), CoreError> {
+        let entry = serde_json::to_vec(value)?;
+        self.append_to_sequence(address, entry).await
+    }
+
+    /// Append an entry to a Sequence, as with `append_to_sequence`, symmetrically encrypted with
+    /// the key it was stored with by `store_encrypted_private_sequence`.
+    pub async fn append_to_encrypted_sequence(
+        &mut self,
+        address: SequenceAddress,
+        entry: SequenceEntry,
+        encryption_key: &SymEncKey,
+    ) -> Result<(), CoreError> {
+        let entry = symmetric_encrypt(&entry, encryption_key, None)?;
+        self.append_to_sequence(address, entry).await
+    }
+
+    /// Append to Sequence, as with `append_to_sequence`, returning the index assigned to the entry.
+    ///
+    /// The index is the one the entry got in the local CRDT replica, i.e. right after the
+    /// entries known locally. Appends are CRDT ops ordered by causality, each one following the
+    /// entries its author had seen: when other clients append concurrently, the replicas on the
+    /// network merge the ops in a deterministic order, so the entry may end up at a different index
+    /// once confirmed. Fetching the Sequence from the network gives the confirmed order.
     ///
     /// # Examples
     /// ```no_run
@@ -506,15 +1301,13 @@ impl Client {
     /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
     /// use std::collections::BTreeMap;
     /// use xor_name::XorName;
-    /// use threshold_crypto::SecretKey;
-    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = SecretKey::random();
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
     /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
     /// let mut client = Client::new(Some(secret_key)).await?;
     /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
     /// let name = XorName::random();
     /// let tag = 10;
     /// let owner = client.public_key().await;
-    /// let new_owner = PublicKey::from( SecretKey::random().public_key() );
     /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
     ///
     /// // Set the access permissions
@@ -526,113 +1319,325 @@ impl Client {
     /// // The returned address can then be used to `append` data to.
     /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
     ///
-    /// let mut sequence = client.get_sequence(address).await?;
-    /// let write_op = sequence.set_owner(new_owner);
-    /// let _ = client.set_sequence_owner(write_op).await?;
+    /// let index = client.append_to_sequence_indexed(address, b"New Entry Value".to_vec()).await?;
+    /// assert_eq!(index, 0);
     /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
     /// ```
-    pub async fn set_sequence_owner(
+    pub async fn append_to_sequence_indexed(
         &mut self,
-        op: SequenceWriteOp<SequenceOwner>,
-    ) -> Result<(), CoreError> {
-        // --------------------------
-        // Payment for PUT
-        // --------------------------
-        let payment_proof = self.create_write_payment_proof().await?;
+        address: SequenceAddress,
+        entry: SequenceEntry,
+    ) -> Result<u64, CoreError> {
+        self.check_writes_allowed()?;
+        self.check_entry_size(&entry)?;
 
-        //---------------------------------
-        // The _actual_ message
-        //---------------------------------
-        let msg_contents = wrap_seq_write(SequenceWrite::SetOwner(op), payment_proof.clone());
-        let message = Self::create_cmd_message(msg_contents);
-        let _ = self.connection_manager.send_cmd(&message).await?;
+        let mirror = self
+            .sequence_mirrors
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&address)
+            .copied();
+        let mirror = match mirror {
+            Some(mirror) => mirror,
+            None => return self.append_with_retries(address, entry).await,
+        };
+
+        let index = self.append_with_retries(address, entry.clone()).await?;
+        if let Err(error) = self.append_with_retries(mirror, entry).await {
+            warn!(
+                "Failed to append to mirror {:?} of Sequence at {:?}: {}",
+                mirror.name(),
+                address.name(),
+                error
+            );
+        }
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(index)
     }
 
-    /// Get the owner of a Sequence.
-    #[allow(dead_code)]
-    async fn get_sequence_owner(
+    // Append an entry to a Sequence, retrying with a fresh replica should our local replica deny
+    // it. `send_cmd` doesn't await the Elders' response, so their denials can't be retried.
+    async fn append_with_retries(
         &mut self,
         address: SequenceAddress,
-    ) -> Result<SequenceOwner, CoreError> {
-        trace!("Get owner of the Sequence Data at {:?}", address.name());
+        entry: SequenceEntry,
+    ) -> Result<u64, CoreError> {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            // After a permissions failure our local replica is likely to be stale,
+            // so any further attempt fetches the Sequence from the network first.
+            let force_refresh = attempts > 1;
+            match self
+                .try_append_to_sequence(address, entry.clone(), force_refresh)
+                .await
+            {
+                Err(CoreError::SequenceAccessDenied {
+                    action: SequenceAction::Append,
+                }) if attempts < MAX_APPEND_ATTEMPTS => {
+                    warn!(
+                        "Append to Sequence at {:?} was denied, refreshing local replica and retrying",
+                        address.name()
+                    );
+                }
+                res => return res,
+            }
+        }
+    }
 
-        // TODO: perhaps we want to grab it directly from the network and update local replica
-        let sequence = self.get_sequence(address).await?;
-        let owner = sequence.owner(sequence.owners_index() - 1).ok_or_else(|| {
-            CoreError::from("Unexpectedly failed to obtain current owner of Sequence")
-        })?;
+    /// Append an entry to a Sequence without awaiting it to be sent to the network.
+    ///
+    /// The entry is appended to the local CRDT replica, as by `append_to_sequence_indexed`, and
+    /// its index returned right away, while paying for it and sending it to the network is left
+    /// to a background task. The entries appended this way are sent one at a time, in order.
+    /// Failing to send one of them doesn't fail this call: the error is kept for
+    /// `drain_background_errors` instead. `flush_background_writes` waits for them all to be sent.
+    ///
+    /// The Sequence of the local replica isn't checked for having been deleted from the network,
+    /// as by `append_to_sequence_indexed` (see `ClientConfig::sequence_deleted_check_interval`),
+    /// so an append to a Sequence deleted meanwhile is paid for nonetheless.
+    ///
+    /// Mirrors registered with `register_sequence_mirror` aren't appended to.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// for reading in 0..10 {
+    ///     let _ = client.append_to_sequence_nowait(address, format!("{}", reading).into_bytes()).await?;
+    /// }
+    ///
+    /// client.flush_background_writes().await;
+    /// for error in client.drain_background_errors() {
+    ///     println!("Reading lost: {}", error);
+    /// }
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn append_to_sequence_nowait(
+        &mut self,
+        address: SequenceAddress,
+        entry: SequenceEntry,
+    ) -> Result<u64, CoreError> {
+        self.check_writes_allowed()?;
+        self.check_entry_size(&entry)?;
 
-        Ok(*owner)
+        // Not checked for being deleted, which would await a query
+        let (index, op) = self.append_locally(address, entry, false, false).await?;
+
+        let (done, completion) = oneshot::channel::<()>();
+        let previous = self
+            .last_background_write
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(completion);
+        let mut client = self.clone();
+        let _ = tokio::spawn(async move {
+            // Sent in order, whether the previous one succeeded or not
+            if let Some(previous) = previous {
+                let _ = previous.await;
+            }
+            if let Err(error) = client.send_append(address, op).await {
+                warn!(
+                    "Failed to send append to Sequence at {:?} in the background: {}",
+                    address.name(),
+                    error
+                );
+                client
+                    .background_errors
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(error);
+            }
+            drop(done);
+        });
+
+        Ok(index)
     }
 
-    /// Set the new owner of a Sequence Data
-    #[allow(dead_code)]
-    async fn sequence_set_owner(
+    /// Wait for all the writes sent in the background by `append_to_sequence_nowait`, from this
+    /// client or any of its clones, to be sent to the network or to fail.
+    pub async fn flush_background_writes(&self) {
+        // Writes sent meanwhile still wait for the ones before them
+        let (done, completion) = oneshot::channel::<()>();
+        let last = self
+            .last_background_write
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(completion);
+        if let Some(last) = last {
+            let _ = last.await;
+        }
+        drop(done);
+    }
+
+    /// Take the errors of the writes sent in the background by `append_to_sequence_nowait`, from
+    /// this client or any of its clones, which failed since last drained, oldest first.
+    pub fn drain_background_errors(&self) -> Vec<CoreError> {
+        std::mem::take(
+            &mut *self
+                .background_errors
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    // The network would reject an entry larger than the maximum, but only once paid for
+    fn check_entry_size(&self, entry: &SequenceEntry) -> Result<(), CoreError> {
+        if entry.len() > self.max_entry_size {
+            return Err(CoreError::EntryTooLarge {
+                size: entry.len(),
+                max: self.max_entry_size,
+            });
+        }
+        Ok(())
+    }
+
+    // Single attempt at appending an entry to a Sequence
+    async fn try_append_to_sequence(
         &mut self,
         address: SequenceAddress,
-        owner: PublicKey,
-    ) -> Result<(), CoreError> {
-        // First we fetch it either from local CRDT replica or from the network if not found
-        let mut sequence = self.get_sequence(address).await?;
+        entry: SequenceEntry,
+        force_refresh: bool,
+    ) -> Result<u64, CoreError> {
+        let (index, op) = self
+            .append_locally(address, entry, force_refresh, true)
+            .await?;
+        self.send_append(address, op).await?;
+        Ok(index)
+    }
+
+    // Append an entry to the local replica of a Sequence, returning its index and the op
+    // to send to the network
+    async fn append_locally(
+        &mut self,
+        address: SequenceAddress,
+        entry: SequenceEntry,
+        force_refresh: bool,
+        check_deleted: bool,
+    ) -> Result<(u64, SequenceWriteOp<SequenceEntry>), CoreError> {
+        // A private Sequence of our local replica may have been deleted since, and
+        // the network would only ignore the append once paid for, so one fetched longer
+        // than the deleted check interval ago is refreshed first
+        let cached = if check_deleted
+            && !force_refresh
+            && address.is_private()
+            && self.needs_deleted_check(address).await
+        {
+            self.cached_sequence(address).await
+        } else {
+            None
+        };
+        // Then we fetch it so we can get the causality info,
+        // either from local CRDT replica or from the network if not found
+        let mut sequence = match cached {
+            Some(cached) => self.refresh_sequence_unless_deleted(cached).await?,
+            None => self.get_sequence_with_opts(address, force_refresh).await?,
+        };
 
         // We do a permissions check just to make sure it won't fail when the operation
         // is broadcasted to the network, assuming our replica is in sync and up to date
-        // with the ownership information compared with the replicas on the network.
-        sequence.check_permission(
-            SequenceAction::ManagePermissions,
-            self.public_id().await.public_key(),
-        )?;
+        // with the permissions and ownership information compared with the replicas on the network.
+        sequence
+            .check_permission(SequenceAction::Append, self.public_key_sync())
+            .map_err(map_sequence_access_error(SequenceAction::Append))?;
 
-        // We can now set the new owner to the Sequence
-        let op = sequence.set_owner(owner);
+        // We can now append the entry to the Sequence, right after the entries we know of
+        let op = sequence.append(entry);
+        let index = sequence.entries_index() - 1;
 
         // Update the local Sequence CRDT replica
-        let _ = self
-            .sequence_cache
-            .lock()
-            .await
-            .put(*sequence.address(), sequence.clone());
+        self.update_cached_sequence(sequence.clone()).await;
 
-        // Finally we can send the mutation to the network's replicas
-        self.set_sequence_owner(op).await
+        Ok((index, op))
     }
 
-    //----------------------
-    // Permissions
-    //---------------------
-
-    /// Mutate sequenced data private permissions
-    /// Wraps msg_contents for payment validation and mutation
-    pub async fn edit_sequence_private_perms(
+    // Pay for and send an append already applied to the local replica
+    async fn send_append(
         &mut self,
-        op: SequenceWriteOp<SequencePrivatePermissions>,
+        address: SequenceAddress,
+        op: SequenceWriteOp<SequenceEntry>,
     ) -> Result<(), CoreError> {
-        // --------------------------
-        // Payment for PUT
-        // --------------------------
-        let payment_proof = self.create_write_payment_proof().await?;
+        // Finally we can send the mutation to the network's replicas
+        if let Err(error) = self
+            .pay_and_write_append_to_sequence_to_network(op.clone())
+            .await
+        {
+            // The local replica has it, which the network doesn't
+            self.invalidate_sequence_cache(address).await;
+            return Err(error);
+        }
 
-        //---------------------------------
-        // The _actual_ message
-        //---------------------------------
-        let msg_contents = wrap_seq_write(
-            SequenceWrite::SetPrivatePermissions(op),
-            payment_proof.clone(),
-        );
-        let message = Self::create_cmd_message(msg_contents);
-        let _ = self.connection_manager.send_cmd(&message).await?;
+        // Keep it to be merged into the Sequence fetched from the network,
+        // until the network's replicas have it
+        if !self.dry_run {
+            self.pending_sequence_ops
+                .lock()
+                .await
+                .entry(address)
+                .or_default()
+                .push(op);
+        }
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 
-    /// Mutate sequenced data public permissions
+    // Whether the Sequence of our local replica was fetched longer than the deleted check
+    // interval ago, so the network may have deleted it meanwhile
+    async fn needs_deleted_check(&self, address: SequenceAddress) -> bool {
+        let now = self.clock.now();
+        match self.sequence_cache.lock().await.peek(&address) {
+            Some((_, fetched_at)) => {
+                now.duration_since(*fetched_at) >= self.sequence_deleted_check_interval
+            }
+            None => false,
+        }
+    }
+
+    // Refresh a Sequence of our local replica from the network, failing with `SequenceDeleted`
+    // should the network no longer hold it, dropping it from our local replica.
+    // The local replica is used as is should the network be unreachable for now.
+    async fn refresh_sequence_unless_deleted(
+        &mut self,
+        cached: Sequence,
+    ) -> Result<Sequence, CoreError> {
+        let address = *cached.address();
+        match self.get_sequence_with_opts(address, true).await {
+            Err(CoreError::DataError(SndError::NoSuchData)) => {
+                warn!("Sequence at {:?} was deleted", address.name());
+                self.invalidate_sequence_cache(address).await;
+                Err(CoreError::SequenceDeleted)
+            }
+            Err(error) if is_transient(&error) => {
+                warn!(
+                    "Failed to refresh Sequence at {:?}, using the local replica: {}",
+                    address.name(),
+                    error
+                );
+                Ok(cached)
+            }
+            res => res,
+        }
+    }
+
+    /// Append data to a sequenced data object
     /// Wraps msg_contents for payment validation and mutation
-    pub async fn edit_sequence_public_perms(
+    async fn pay_and_write_append_to_sequence_to_network(
         &mut self,
-        op: SequenceWriteOp<SequencePublicPermissions>,
+        op: SequenceWriteOp<Vec<u8>>,
     ) -> Result<(), CoreError> {
+        let write = SequenceWrite::Edit(op);
+        if self.skip_sequence_write_in_dry_run(&write)? {
+            return Ok(());
+        }
+
         // --------------------------
         // Payment for PUT
         // --------------------------
@@ -641,198 +1646,4595 @@ impl Client {
         //---------------------------------
         // The _actual_ message
         //---------------------------------
-        let msg_contents = wrap_seq_write(
-            SequenceWrite::SetPublicPermissions(op),
-            payment_proof.clone(),
-        );
+        let msg_contents = wrap_seq_write(write, payment_proof);
         let message = Self::create_cmd_message(msg_contents);
         let _ = self.connection_manager.send_cmd(&message).await?;
 
-        self.apply_write_payment_to_local_actor(payment_proof).await
+        Ok(())
     }
 
-    /// Get the set of Permissions of a Public Sequence.
-    #[allow(dead_code)]
-    async fn get_sequence_pub_permissions(
+    // In dry-run mode writes are neither paid for nor sent to the network, only logged
+    fn skip_sequence_write_in_dry_run(&self, write: &SequenceWrite) -> Result<bool, CoreError> {
+        self.check_writes_allowed()?;
+        if self.dry_run {
+            info!("Dry run, not sending Sequence write: {:?}", write);
+        }
+        Ok(self.dry_run)
+    }
+
+    /// Store a new public sequenced data object
+    /// Wraps msg_contents for payment validation and mutation
+    pub(crate) async fn pay_and_write_sequence_to_network(
         &mut self,
-        address: SequenceAddress,
-    ) -> Result<SequencePublicPermissions, CoreError> {
-        trace!(
-            "Get permissions from Public Sequence Data at {:?}",
-            address.name()
-        );
+        data: Sequence,
+    ) -> Result<(), CoreError> {
+        let span = info_span!("pay_and_write_sequence_to_network", address = ?data.address());
+        async {
+            let write = SequenceWrite::New(data);
+            if self.skip_sequence_write_in_dry_run(&write)? {
+                return Ok(());
+            }
 
-        // TODO: perhaps we want to grab it directly from the network and update local replica
-        let sequence = self.get_sequence(address).await?;
-        let perms = sequence
-            .pub_permissions(sequence.permissions_index() - 1)
-            .map_err(CoreError::from)?;
+            // --------------------------
+            // Payment for PUT
+            // --------------------------
+            let payment_proof = self.create_write_payment_proof().await?;
 
-        Ok(perms.clone())
-    }
+            //---------------------------------
+            // The _actual_ message
+            //---------------------------------
+            let msg_contents = wrap_seq_write(write, payment_proof);
+            let message = Self::create_cmd_message(msg_contents);
+            let _ = self.connection_manager.send_cmd(&message).await?;
 
-    /// Get the set of Permissions of a Private Sequence.
-    #[allow(dead_code)]
-    async fn get_sequence_private_permissions(
-        &mut self,
-        address: SequenceAddress,
-    ) -> Result<SequencePrivatePermissions, CoreError> {
-        trace!(
-            "Get permissions from Private Sequence Data at {:?}",
-            address.name()
-        );
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
 
-        // TODO: perhaps we want to grab it directly from the network and update local replica
-        let sequence = self.get_sequence(address).await?;
-        let perms = sequence
-            .private_permissions(sequence.permissions_index() - 1)
-            .map_err(CoreError::from)?;
+    //----------------------
+    // Get Sequence
+    //---------------------
 
-        Ok(perms.clone())
+    /// Get Sequence Data from the Network
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// let _data = client.get_sequence(address).await?;
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence(&mut self, address: SequenceAddress) -> Result<Sequence, CoreError> {
+        self.get_sequence_with_opts(address, false).await
     }
 
-    /// Get the set of Permissions for a specific user in a Sequence.
-    #[allow(dead_code)]
-    async fn get_sequence_user_permissions(
+    /// Get Sequence Data from the Network, optionally bypassing the local CRDT replica
+    ///
+    /// When `force_refresh` is `true` the local replica is not read, the Sequence is always
+    /// fetched from the network and replaces the local replica. This is useful when the local
+    /// replica may be out of sync with the permissions/owner held by the network.
+    ///
+    /// Whenever the Sequence is fetched from the network, the entries this client appended but
+    /// which the network's replicas may not have applied yet are merged into it, so they aren't
+    /// lost. Being CRDT ops, they end up in the same order as on the network once applied there.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// // Skip the local replica and get the Sequence from the network
+    /// let _data = client.get_sequence_with_opts(address, true).await?;
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_with_opts(
         &mut self,
         address: SequenceAddress,
-        user: SequenceUser,
-    ) -> Result<SequenceUserPermissions, CoreError> {
+        force_refresh: bool,
+    ) -> Result<Sequence, CoreError> {
         trace!(
-            "Get permissions for user {:?} from Sequence Data at {:?}",
-            user,
-            address.name()
+            "Get Sequence Data at {:?} (force refresh: {})",
+            address.name(),
+            force_refresh
         );
+        // First try to fetch it from local CRDT replica, unless we were asked to refresh it.
+        // Once older than the TTL, it's refreshed from the network, to mitigate the risk of
+        // successfully applying mutations locally but which can fail on other replicas,
+        // e.g. due to being out of sync with permissions/owner
+        if !force_refresh {
+            if let Some(sequence) = self.cached_sequence(address).await {
+                trace!("Sequence found in local CRDT replica");
+                return Ok(sequence);
+            }
 
-        // TODO: perhaps we want to grab it directly from the network and update local replica
-        let sequence = self.get_sequence(address).await?;
-        let perms = sequence
-            .user_permissions(user, sequence.permissions_index() - 1)
-            .map_err(CoreError::from)?;
+            trace!("Sequence not found in local CRDT replica, or too old");
+        }
 
-        Ok(perms)
-    }
+        // Let's fetch it from the network then
+        let sequence = self.fetch_sequence_coalesced(address).await?;
 
-    /// Set permissions to Public Sequence Data
-    #[allow(dead_code)]
-    async fn sequence_set_pub_permissions(
-        &mut self,
-        address: SequenceAddress,
-        permissions: BTreeMap<SequenceUser, SequencePubUserPermissions>,
-    ) -> Result<(), CoreError> {
-        // First we fetch it either from local CRDT replica or from the network if not found
-        let mut sequence = self.get_sequence(address).await?;
+        // Don't lose the appends the network's replicas don't have yet
+        let sequence = self.merge_pending_sequence_ops(sequence).await?;
 
-        // We do a permissions check just to make sure it won't fail when the operation
-        // is broadcasted to the network, assuming our replica is in sync and up to date
-        // with the permissions information compared with the replicas on the network.
-        sequence.check_permission(
-            SequenceAction::ManagePermissions,
-            self.public_id().await.public_key(),
-        )?;
+        trace!("Store Sequence in local CRDT replica");
+        // Store in local Sequence CRDT replica
+        self.cache_sequence(sequence.clone()).await;
 
-        // We can now set the new permissions to the Sequence
-        let op = sequence.set_pub_permissions(permissions)?;
+        Ok(sequence)
+    }
 
-        // Update the local Sequence CRDT replica
-        let _ = self
-            .sequence_cache
-            .lock()
+    /// Fetch several Sequences from the Network into the local CRDT replica, so that reading
+    /// them later doesn't need to wait on the network.
+    ///
+    /// The Sequences are fetched concurrently, with a bounded number of fetches in flight, and
+    /// those already in the local replica aren't fetched again. The results are in the same
+    /// order as the addresses, so failing to fetch one Sequence doesn't fail the others.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{SequenceAddress, Money};
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// let addresses: Vec<SequenceAddress> = (0..10)
+    ///     .map(|_| SequenceAddress::Public { name: XorName::random(), tag: 10 })
+    ///     .collect();
+    ///
+    /// for res in client.prefetch_sequences(addresses).await {
+    ///     if let Err(error) = res {
+    ///         println!("Failed to prefetch a Sequence: {}", error);
+    ///     }
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn prefetch_sequences(
+        &mut self,
+        addresses: Vec<SequenceAddress>,
+    ) -> Vec<Result<(), CoreError>> {
+        trace!("Prefetch {} Sequences", addresses.len());
+
+        stream::iter(addresses)
+            .map(|address| {
+                let mut client = self.clone();
+                // Sequences in the local replica are read from it, rather than fetched again
+                async move { client.get_sequence(address).await.map(|_| ()) }
+            })
+            .buffered(MAX_CONCURRENT_SEQUENCE_FETCHES)
+            .collect()
             .await
-            .put(*sequence.address(), sequence.clone());
+    }
 
-        // Finally we can send the mutation to the network's replicas
-        self.edit_sequence_public_perms(op).await
+    /// Get Sequence Data from the Network, failing with `CoreError::RequestTimeout` if the
+    /// network doesn't reply before the timeout elapses.
+    ///
+    /// A Sequence found in the local CRDT replica is returned right away, whatever the timeout.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::{collections::BTreeMap, time::Duration};
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// let _data = client.get_sequence_timeout(address, Duration::from_secs(5)).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_timeout(
+        &mut self,
+        address: SequenceAddress,
+        timeout: Duration,
+    ) -> Result<Sequence, CoreError> {
+        trace!(
+            "Get Sequence Data at {:?} (timeout: {:?})",
+            address.name(),
+            timeout
+        );
+        if let Some(sequence) = self.cached_sequence(address).await {
+            trace!("Sequence found in local CRDT replica");
+            return Ok(sequence);
+        }
+
+        let sequence = with_deadline(timeout, self.fetch_sequence_coalesced(address)).await?;
+
+        // Don't lose the appends the network's replicas don't have yet
+        let sequence = self.merge_pending_sequence_ops(sequence).await?;
+        self.cache_sequence(sequence.clone()).await;
+
+        Ok(sequence)
     }
 
-    /// Set permissions to Private Sequence Data
-    #[allow(dead_code)]
-    async fn sequence_set_private_permissions(
+    /// Wait until the entry at the given index of a Sequence is present on the network.
+    ///
+    /// The Sequence is fetched from the network until its replicas have the entry, e.g. one
+    /// appended with `append_to_sequence_indexed`, failing with `CoreError::RequestTimeout`
+    /// if they still don't once the timeout elapses.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::{collections::BTreeMap, time::Duration};
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// let index = client.append_to_sequence_indexed(address, b"New Entry Value".to_vec()).await?;
+    /// client.await_sequence_entry_confirmed(address, index, Duration::from_secs(10)).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn await_sequence_entry_confirmed(
         &mut self,
         address: SequenceAddress,
-        permissions: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+        index: u64,
+        timeout: Duration,
     ) -> Result<(), CoreError> {
-        // First we fetch it either from local CRDT replica or from the network if not found
-        let mut sequence = self.get_sequence(address).await?;
+        trace!(
+            "Await confirmation of entry {} of Sequence Data at {:?}",
+            index,
+            address.name()
+        );
 
-        // We do a permissions check just to make sure it won't fail when the operation
-        // is broadcasted to the network, assuming our replica is in sync and up to date
-        // with the permissions information compared with the replicas on the network.
-        // TODO: if it fails, try to sync-up perms with rmeote replicas and try once more
-        sequence.check_permission(
-            SequenceAction::ManagePermissions,
-            self.public_id().await.public_key(),
-        )?;
+        let sequence = with_deadline(timeout, self.poll_sequence_entry(address, index)).await?;
 
-        // We can now set the new permissions to the Sequence
-        let op = sequence.set_private_permissions(permissions)?;
+        // We might as well refresh the local replica with it
+        let sequence = self.merge_pending_sequence_ops(sequence).await?;
+        self.cache_sequence(sequence).await;
 
-        // Update the local Sequence CRDT replica
-        let _ = self
+        Ok(())
+    }
+
+    // Fetch a Sequence from the network until its replicas have the entry at the given index
+    async fn poll_sequence_entry(
+        &mut self,
+        address: SequenceAddress,
+        index: u64,
+    ) -> Result<Sequence, CoreError> {
+        loop {
+            let sequence = self.fetch_sequence_from_network(address).await?;
+            if sequence.entries_index() > index {
+                return Ok(sequence);
+            }
+            time::delay_for(SEQUENCE_CONFIRMATION_POLL_INTERVAL).await;
+        }
+    }
+
+    // Get a Sequence from the network, sharing the result of the fetch of the same Sequence
+    // already in flight for another reader, if any, rather than querying the network again
+    async fn fetch_sequence_coalesced(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<Sequence, CoreError> {
+        let waiting = {
+            let mut fetches = self
+                .in_flight_sequence_fetches
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            match fetches.get_mut(&address) {
+                Some(waiters) => {
+                    let (sender, receiver) = oneshot::channel();
+                    waiters.push(sender);
+                    Some(receiver)
+                }
+                None => {
+                    let _ = fetches.insert(address, Vec::new());
+                    None
+                }
+            }
+        };
+
+        if let Some(receiver) = waiting {
+            trace!("Waiting on the fetch of Sequence at {:?}", address.name());
+            return match receiver.await {
+                Ok(sequence) => Ok(sequence),
+                // It failed, let's see if we're luckier
+                Err(_) => self.fetch_sequence_from_network(address).await,
+            };
+        }
+
+        let fetch = InFlightSequenceFetch {
+            fetches: self.in_flight_sequence_fetches.clone(),
+            address,
+            finished: false,
+        };
+        let sequence = self.fetch_sequence_from_network(address).await?;
+        for waiter in fetch.finish() {
+            let _ = waiter.send(sequence.clone());
+        }
+
+        Ok(sequence)
+    }
+
+    // Get a Sequence as held by the network's replicas, bypassing the local CRDT replica
+    async fn fetch_sequence_from_network(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<Sequence, CoreError> {
+        match self
+            .send_query(wrap_seq_read(SequenceRead::Get(address)))
+            .await?
+        {
+            QueryResponse::GetSequence(res) => {
+                res.map_err(map_sequence_access_error(SequenceAction::Read))
+            }
+            other => Err(CoreError::unexpected_query_response("GetSequence", &other)),
+        }
+    }
+
+    /// Check whether a Sequence exists on the Network
+    ///
+    /// The network is always queried. Sequences which are found are stored in the
+    /// local CRDT replica, as by `get_sequence`, but a miss is never cached.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::SequenceAddress;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let address = SequenceAddress::Public { name: XorName::random(), tag: 10 };
+    ///
+    /// assert!(!client.sequence_exists(address).await?);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn sequence_exists(&mut self, address: SequenceAddress) -> Result<bool, CoreError> {
+        trace!("Check if Sequence Data exists at {:?}", address.name());
+
+        let res = match self
+            .send_query(wrap_seq_read(SequenceRead::Get(address)))
+            .await?
+        {
+            QueryResponse::GetSequence(res) => res,
+            other => return Err(CoreError::unexpected_query_response("GetSequence", &other)),
+        };
+
+        match res {
+            Ok(sequence) => {
+                // Don't lose the appends the network's replicas don't have yet
+                let sequence = self.merge_pending_sequence_ops(sequence).await?;
+                // Store in local Sequence CRDT replica
+                self.cache_sequence(sequence).await;
+                Ok(true)
+            }
+            Err(SndError::NoSuchData) => Ok(false),
+            Err(error) => Err(map_sequence_access_error(SequenceAction::Read)(error)),
+        }
+    }
+
+    /// Compare the local CRDT replica of a Sequence with the network's, to debug sync issues.
+    ///
+    /// The Sequence is fetched from the network, but the local replica isn't updated with it,
+    /// even if stale. Without a local replica, everything the network holds is reported as
+    /// remote only.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    ///
+    /// let diff = client.diff_sequence(address).await?;
+    /// if !diff.is_empty() {
+    ///     println!("Entries not applied by the network yet: {:?}", diff.local_only_entries);
+    /// }
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn diff_sequence(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<SequenceDiff, CoreError> {
+        trace!(
+            "Diff local replica of Sequence Data at {:?} with the network's",
+            address.name()
+        );
+
+        let local = self
             .sequence_cache
             .lock()
             .await
-            .put(*sequence.address(), sequence.clone());
+            .peek(&address)
+            .map(|(sequence, _)| sequence.clone());
+        let remote = SequenceReplicaState::of(&self.fetch_sequence_from_network(address).await?)?;
+        let local = match local {
+            Some(sequence) => SequenceReplicaState::of(&sequence)?,
+            None => SequenceReplicaState::default(),
+        };
+
+        Ok(SequenceDiff {
+            local_only_entries: only_in(&local.entries, &remote.entries, |(_, entry)| entry),
+            remote_only_entries: only_in(&remote.entries, &local.entries, |(_, entry)| entry),
+            local_only_owners: only_in(&local.owners, &remote.owners, |owner| owner),
+            remote_only_owners: only_in(&remote.owners, &local.owners, |owner| owner),
+            local_only_permissions: only_in(&local.permissions, &remote.permissions, |perms| perms),
+            remote_only_permissions: only_in(&remote.permissions, &local.permissions, |perms| {
+                perms
+            }),
+        })
+    }
 
-        // Finally we can send the mutation to the network's replicas
-        self.edit_sequence_private_perms(op).await
+    /// Get the scope of the Sequence at the given address, either public or private.
+    ///
+    /// The scope is encoded in the address itself, so the network isn't queried, and
+    /// whether a Sequence is actually stored at the address isn't checked.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_core::client::sequence_apis::Scope;
+    /// use safe_nd::SequenceAddress;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let address = SequenceAddress::Public { name: XorName::random(), tag: 10 };
+    ///
+    /// assert_eq!(client.sequence_scope(address).await?, Scope::Public);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn sequence_scope(&mut self, address: SequenceAddress) -> Result<Scope, CoreError> {
+        Ok(scope_of(address))
+    }
+
+    /// Mirror the appends to the `primary` Sequence onto the `mirror` one, for redundancy.
+    ///
+    /// Every entry appended to `primary` with `append_to_sequence` or `append_to_sequence_indexed`
+    /// by this client, or any of its clones, is then appended to `mirror` too, which is paid for
+    /// separately. Failing to append to the mirror is only logged, without failing the append to
+    /// `primary`. Mirrors aren't followed, so appends to `mirror` aren't mirrored in turn.
+    /// Registering another mirror for `primary` replaces the previous one.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let primary = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    /// let mirror = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// client.register_sequence_mirror(primary, mirror);
+    /// client.append_to_sequence(primary, b"Replicated Entry".to_vec()).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub fn register_sequence_mirror(&self, primary: SequenceAddress, mirror: SequenceAddress) {
+        trace!(
+            "Mirror appends to Sequence Data at {:?} onto {:?}",
+            primary.name(),
+            mirror.name()
+        );
+        let _ = self
+            .sequence_mirrors
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(primary, mirror);
+    }
+
+    /// Stop mirroring the appends to the `primary` Sequence, returning the mirror it had, if any.
+    pub fn unregister_sequence_mirror(&self, primary: SequenceAddress) -> Option<SequenceAddress> {
+        self.sequence_mirrors
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&primary)
+    }
+
+    /// Get the addresses of the Sequences stored by this client, or any of its clones, with
+    /// `store_private_sequence` or `store_public_sequence`, oldest first. Sequences deleted
+    /// through this client are left out.
+    ///
+    /// This is only local bookkeeping, starting empty for every new client unless restored
+    /// with `Client::import_session`: the network isn't queried, so Sequences stored by other
+    /// clients with the same keys aren't listed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// assert_eq!(client.my_sequences(), vec![address]);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub fn my_sequences(&self) -> Vec<SequenceAddress> {
+        self.created_sequences
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    // Remember a Sequence was stored by this client, unless only pretending to in dry-run mode
+    fn record_created_sequence(&self, address: SequenceAddress) {
+        if self.dry_run {
+            return;
+        }
+
+        let mut created = self
+            .created_sequences
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if !created.contains(&address) {
+            created.push(address);
+        }
+    }
+
+    // Forget a Sequence stored by this client, once deleted
+    fn forget_created_sequence(&self, address: SequenceAddress) {
+        self.created_sequences
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|created| *created != address);
+    }
+
+    /// Get the last data entry from a Sequence Data.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Another New Entry Value".to_vec()).await?;
+    ///
+    /// // Now we can retrieve the alst entry in the sequence:
+    /// let (_position, last_entry) = client.get_sequence_last_entry(address).await?;
+    ///
+    /// assert_eq!(last_entry, b"Another New Entry Value".to_vec());
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    #[allow(dead_code)]
+    pub async fn get_sequence_last_entry(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<(u64, SequenceEntry), CoreError> {
+        trace!(
+            "Get latest entry from Sequence Data at {:?}",
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        match sequence.last_entry() {
+            Some(entry) => Ok((sequence.entries_index() - 1, entry.to_vec())),
+            None => Err(CoreError::from(safe_nd::Error::NoSuchEntry)),
+        }
+    }
+
+    /// Get the number of entries appended to a Sequence Data.
+    ///
+    /// The network doesn't currently support a length-only query, so this is served from the
+    /// Sequence obtained with `get_sequence`, i.e. from the local CRDT replica if present.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    ///
+    /// let count = client.get_sequence_entries_count(address).await?;
+    ///
+    /// assert_eq!(count, 1);
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_entries_count(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<u64, CoreError> {
+        trace!(
+            "Get entries count from Sequence Data at {:?}",
+            address.name()
+        );
+
+        // TODO: use a length-only query once the network supports one
+        let sequence = self.get_sequence(address).await?;
+        Ok(sequence.entries_index())
+    }
+
+    /// Get the first data entry from a Sequence Data.
+    ///
+    /// The returned index is the absolute position of the oldest entry still present
+    /// in the Sequence, which is not necessarily `0`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Another New Entry Value".to_vec()).await?;
+    ///
+    /// // Now we can retrieve the first entry in the sequence:
+    /// let (_position, first_entry) = client.get_sequence_first_entry(address).await?;
+    ///
+    /// assert_eq!(first_entry, b"New Entry Value".to_vec());
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_first_entry(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<(u64, SequenceEntry), CoreError> {
+        trace!("Get first entry from Sequence Data at {:?}", address.name());
+
+        let sequence = self.get_sequence(address).await?;
+        let entries = sequence
+            .in_range(SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0))
+            .unwrap_or_default();
+
+        // The entries index counts every entry ever appended, so if the head of the
+        // Sequence is no longer present the first entry isn't found at index 0.
+        let first_index = sequence.entries_index() - entries.len() as u64;
+        match entries.into_iter().next() {
+            Some(entry) => Ok((first_index, entry)),
+            None => Err(CoreError::from(safe_nd::Error::NoSuchEntry)),
+        }
+    }
+
+    /// Get the entry at the given absolute index of a Sequence, i.e. counting from its very
+    /// first entry, fails with `NoSuchEntry` if the Sequence has no entry there.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"First Entry".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Second Entry".to_vec()).await?;
+    ///
+    /// let entry = client.get_sequence_entry(address, 1).await?;
+    /// assert_eq!(entry, b"Second Entry".to_vec());
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_entry(
+        &mut self,
+        address: SequenceAddress,
+        index: u64,
+    ) -> Result<SequenceEntry, CoreError> {
+        trace!(
+            "Get entry {} from Sequence Data at {:?}",
+            index,
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        if index >= sequence.entries_index() {
+            return Err(CoreError::from(safe_nd::Error::NoSuchEntry));
+        }
+
+        sequence
+            .in_range(
+                SequenceIndex::FromStart(index),
+                SequenceIndex::FromStart(index + 1),
+            )
+            .and_then(|entries| entries.into_iter().next())
+            .ok_or_else(|| CoreError::from(safe_nd::Error::NoSuchEntry))
+    }
+
+    /// Get all the entries of a Sequence, each paired with its absolute index, oldest first.
+    ///
+    /// As with `get_sequence_first_entry`, the indices of a Sequence which no longer holds its
+    /// head start after 0.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"First Entry".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Second Entry".to_vec()).await?;
+    ///
+    /// for (index, entry) in client.get_sequence_entries_indexed(address).await? {
+    ///     println!("{}: {:?}", index, entry);
+    /// }
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_entries_indexed(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<Vec<(u64, SequenceEntry)>, CoreError> {
+        trace!(
+            "Get indexed entries from Sequence Data at {:?}",
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        let entries = sequence
+            .in_range(SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0))
+            .unwrap_or_default();
+
+        let first_index = sequence.entries_index() - entries.len() as u64;
+        Ok((first_index..).zip(entries).collect())
+    }
+
+    /// Get a set of Entries for the requested range from a Sequence.
+    ///
+    /// `FromEnd` indices are resolved against the number of entries of the Sequence. The range
+    /// is rejected with `CoreError::InvalidRange` if the Sequence has no entries, if an index is
+    /// out of its bounds, or if the start comes after the end.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions, SequenceIndex};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Another New Entry Value".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Third Entry Value".to_vec()).await?;
+    ///
+    /// // Now we can retrieve the alst entry in the sequence:
+    /// let entries = client.get_sequence_range(address, (SequenceIndex::FromStart(1), SequenceIndex::FromEnd(0) )).await?;
+    ///
+    /// assert_eq!(entries[0], b"Another New Entry Value".to_vec());
+    /// assert_eq!(entries[1], b"Third Entry Value".to_vec());
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    #[allow(dead_code)]
+    pub async fn get_sequence_range(
+        &mut self,
+        address: SequenceAddress,
+        range: (SequenceIndex, SequenceIndex),
+    ) -> Result<SequenceEntries, CoreError> {
+        trace!(
+            "Get range of entries from Sequence Data at {:?}",
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        let (start, end) = resolve_sequence_range(range, sequence.entries_index())?;
+        sequence
+            .in_range(
+                SequenceIndex::FromStart(start),
+                SequenceIndex::FromStart(end),
+            )
+            .ok_or_else(|| CoreError::from(safe_nd::Error::NoSuchEntry))
+    }
+
+    /// Get the entries of a Sequence as of the given version, i.e. all of them from the first
+    /// one up to and including the entry at index `version`.
+    ///
+    /// This is the same as a range from the start to `version + 1`. Fails with
+    /// `CoreError::InvalidRange` if the Sequence has no entry at that index yet.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    /// client.append_to_sequence(address, b"First Value".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Second Value".to_vec()).await?;
+    ///
+    /// // The Sequence as it was before the second append
+    /// let entries = client.get_sequence_at_version(address, 0).await?;
+    /// assert_eq!(entries, vec![b"First Value".to_vec()]);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_at_version(
+        &mut self,
+        address: SequenceAddress,
+        version: u64,
+    ) -> Result<SequenceEntries, CoreError> {
+        trace!(
+            "Get entries from Sequence Data at {:?} as of version {}",
+            address.name(),
+            version
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        let entries_index = sequence.entries_index();
+        if version >= entries_index {
+            return Err(CoreError::InvalidRange {
+                reason: format!(
+                    "version {} is beyond the Sequence's {} entries",
+                    version, entries_index
+                ),
+            });
+        }
+
+        sequence
+            .in_range(
+                SequenceIndex::FromStart(0),
+                SequenceIndex::FromStart(version + 1),
+            )
+            .ok_or_else(|| CoreError::from(safe_nd::Error::NoSuchEntry))
+    }
+
+    /// Get ranges of entries from several Sequences, as with `get_sequence_range`.
+    ///
+    /// The ranges are fetched concurrently, with a bounded number of fetches in flight. The
+    /// results are in the same order as the requests, so failing to get one range doesn't fail
+    /// the others.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, SequenceAddress, SequenceIndex};
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// let latest = (SequenceIndex::FromEnd(1), SequenceIndex::FromEnd(0));
+    /// let requests: Vec<_> = (0..3)
+    ///     .map(|_| (SequenceAddress::Public { name: XorName::random(), tag: 10 }, latest))
+    ///     .collect();
+    ///
+    /// for res in client.get_multi_sequence_ranges(requests).await {
+    ///     match res {
+    ///         Ok(entries) => println!("Latest entries: {:?}", entries),
+    ///         Err(error) => println!("Failed to get entries: {}", error),
+    ///     }
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_multi_sequence_ranges(
+        &mut self,
+        requests: Vec<(SequenceAddress, (SequenceIndex, SequenceIndex))>,
+    ) -> Vec<Result<SequenceEntries, CoreError>> {
+        trace!("Get ranges of entries from {} Sequences", requests.len());
+
+        stream::iter(requests)
+            .map(|(address, range)| {
+                let mut client = self.clone();
+                async move { client.get_sequence_range(address, range).await }
+            })
+            .buffered(MAX_CONCURRENT_SEQUENCE_FETCHES)
+            .collect()
+            .await
+    }
+
+    /// Get a range of entries from a Sequence, as with `get_sequence_range`, each deserialised
+    /// with bincode into a `T`, as appended with `append_typed`.
+    ///
+    /// Fails with `CoreError::Deserialization` should any of the entries not be a valid `T`.
+    pub async fn get_sequence_range_typed<T: DeserializeOwned>(
+        &mut self,
+        address: SequenceAddress,
+        range: (SequenceIndex, SequenceIndex),
+    ) -> Result<Vec<T>, CoreError> {
+        self.get_sequence_range(address, range)
+            .await?
+            .iter()
+            .map(|entry| {
+                deserialize(entry).map_err(|error| CoreError::Deserialization(error.to_string()))
+            })
+            .collect()
+    }
+
+    /// Get a range of entries from a Sequence, as with `get_sequence_range`, each parsed as
+    /// UTF-8 JSON, as appended with `append_json`.
+    ///
+    /// An entry which isn't valid JSON gives a `CoreError::Deserialization` in its place,
+    /// without failing the other entries.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, SequenceIndex};
+    /// use serde_json::json;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// client.append_json(address, &json!({ "temperature": 21 })).await?;
+    /// let entries = client
+    ///     .get_sequence_range_json(address, (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)))
+    ///     .await?;
+    /// assert_eq!(entries[0].as_ref().ok(), Some(&json!({ "temperature": 21 })));
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_range_json(
+        &mut self,
+        address: SequenceAddress,
+        range: (SequenceIndex, SequenceIndex),
+    ) -> Result<Vec<Result<serde_json::Value, CoreError>>, CoreError> {
+        Ok(self
+            .get_sequence_range(address, range)
+            .await?
+            .iter()
+            .map(|entry| {
+                serde_json::from_slice(entry)
+                    .map_err(|error| CoreError::Deserialization(error.to_string()))
+            })
+            .collect())
+    }
+
+    /// Get a range of entries from a Sequence, as with `get_sequence_range`, each decrypted with
+    /// the key the Sequence was stored with by `store_encrypted_private_sequence`.
+    ///
+    /// Fails with `CoreError::SymmetricDecipherFailure` should any of the entries not have been
+    /// encrypted with that key.
+    pub async fn get_encrypted_sequence_range(
+        &mut self,
+        address: SequenceAddress,
+        range: (SequenceIndex, SequenceIndex),
+        encryption_key: &SymEncKey,
+    ) -> Result<SequenceEntries, CoreError> {
+        self.get_sequence_range(address, range)
+            .await?
+            .iter()
+            .map(|entry| symmetric_decrypt(entry, encryption_key))
+            .collect()
+    }
+
+    /// Get a stream of all the Entries of a Sequence, each along with its index.
+    ///
+    /// Entries are lazily fetched in pages of `SEQUENCE_ENTRY_STREAM_PAGE_SIZE` entries
+    /// using `get_sequence_range`. Any error obtaining a page is yielded as an `Err` item,
+    /// after which the stream ends.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use futures::stream::StreamExt;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    /// client.append_to_sequence(address, b"New Entry Value".to_vec()).await?;
+    /// client.append_to_sequence(address, b"Another New Entry Value".to_vec()).await?;
+    ///
+    /// let entries = client.sequence_entry_stream(address).await?;
+    /// let entries: Vec<_> = entries.collect().await;
+    ///
+    /// assert_eq!(entries.len(), 2);
+    ///
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn sequence_entry_stream(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<impl Stream<Item = Result<(u64, SequenceEntry), CoreError>>, CoreError> {
+        self.sequence_entry_stream_with_page_size(address, SEQUENCE_ENTRY_STREAM_PAGE_SIZE)
+            .await
+    }
+
+    /// Get a stream of all the Entries of a Sequence, fetching `page_size` entries at a time.
+    ///
+    /// See `sequence_entry_stream` for details.
+    pub async fn sequence_entry_stream_with_page_size(
+        &mut self,
+        address: SequenceAddress,
+        page_size: u64,
+    ) -> Result<impl Stream<Item = Result<(u64, SequenceEntry), CoreError>>, CoreError> {
+        trace!(
+            "Stream entries from Sequence Data at {:?} in pages of {}",
+            address.name(),
+            page_size
+        );
+
+        if page_size == 0 {
+            return Err(CoreError::from(
+                "Sequence entry stream page size must not be 0",
+            ));
+        }
+
+        let entries_count = self.get_sequence_entries_count(address).await?;
+        let client = self.clone();
+
+        // Each step fetches the page starting at `start`, the state being `None` once
+        // an error was found so the stream ends after yielding it.
+        let pages = stream::unfold(Some(0), move |start| {
+            let mut client = client.clone();
+            async move {
+                let start = start?;
+                if start >= entries_count {
+                    return None;
+                }
+
+                let end = std::cmp::min(start + page_size, entries_count);
+                let range = (
+                    SequenceIndex::FromStart(start),
+                    SequenceIndex::FromStart(end),
+                );
+                match client.get_sequence_range(address, range).await {
+                    Ok(entries) => {
+                        let page: Vec<_> = entries
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, entry)| Ok((start + i as u64, entry)))
+                            .collect();
+                        Some((stream::iter(page), Some(end)))
+                    }
+                    Err(error) => Some((stream::iter(vec![Err(error)]), None)),
+                }
+            }
+        });
+
+        Ok(pages.flatten())
+    }
+
+    //----------------------
+    // Local CRDT replica
+    //---------------------
+
+    /// Remove a single Sequence from the local CRDT replica
+    ///
+    /// The next read of this Sequence will be fetched from the network.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::SequenceAddress;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// let address = SequenceAddress::Public { name: XorName::random(), tag: 10 };
+    ///
+    /// client.invalidate_sequence_cache(address).await;
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn invalidate_sequence_cache(&self, address: SequenceAddress) {
+        trace!(
+            "Remove Sequence at {:?} from local CRDT replica",
+            address.name()
+        );
+        let _ = self.sequence_cache.lock().await.pop(&address);
+        let _ = self.pending_sequence_ops.lock().await.remove(&address);
+    }
+
+    /// Remove all Sequences from the local CRDT replica
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    ///
+    /// client.clear_sequence_cache().await;
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn clear_sequence_cache(&self) {
+        trace!("Clear all Sequences from local CRDT replica");
+        self.sequence_cache.lock().await.clear();
+        self.pending_sequence_ops.lock().await.clear();
+    }
+
+    // Apply to a Sequence fetched from the network the appends we sent which its replicas
+    // may not have applied yet. Applying an op already applied does nothing, so those which
+    // don't add an entry are known to the network's replicas, and they're forgotten.
+    // The appends are sent one after the other, so those the replicas have moved past without
+    // applying them, since they applied a later one, were rejected and are forgotten too.
+    async fn merge_pending_sequence_ops(
+        &self,
+        mut sequence: Sequence,
+    ) -> Result<Sequence, CoreError> {
+        let mut pending_ops = self.pending_sequence_ops.lock().await;
+        let ops = match pending_ops.get(sequence.address()) {
+            Some(ops) => ops.clone(),
+            None => return Ok(sequence),
+        };
+
+        let mut applied_by_network = Vec::with_capacity(ops.len());
+        for op in &ops {
+            let mut merged = sequence.clone();
+            merged.apply_data_op(op.clone())?;
+            applied_by_network.push(merged.entries_index() == sequence.entries_index());
+        }
+
+        let still_pending = match applied_by_network.iter().rposition(|applied| *applied) {
+            Some(last_applied) => {
+                let rejected = applied_by_network[..last_applied]
+                    .iter()
+                    .filter(|applied| !**applied)
+                    .count();
+                if rejected > 0 {
+                    warn!(
+                        "Forgetting {} appends rejected by the network's replicas",
+                        rejected
+                    );
+                }
+                ops[last_applied + 1..].to_vec()
+            }
+            None => ops,
+        };
+
+        if still_pending.is_empty() {
+            trace!("All appends applied by the network's replicas");
+            let _ = pending_ops.remove(sequence.address());
+            return Ok(sequence);
+        }
+
+        trace!(
+            "Merged {} entries not yet applied by the network's replicas",
+            still_pending.len()
+        );
+        for op in &still_pending {
+            sequence.apply_data_op(op.clone())?;
+        }
+        let _ = pending_ops.insert(*sequence.address(), still_pending);
+
+        Ok(sequence)
+    }
+
+    // Get a Sequence from the local CRDT replica, unless it was fetched longer than the TTL ago
+    async fn cached_sequence(&self, address: SequenceAddress) -> Option<Sequence> {
+        let now = self.clock.now();
+        let mut cache = self.sequence_cache.lock().await;
+        let sequence = match cache.get(&address) {
+            Some((sequence, fetched_at))
+                if now.duration_since(*fetched_at) < self.sequence_cache_ttl =>
+            {
+                Some(sequence.clone())
+            }
+            _ => None,
+        };
+
+        #[cfg(feature = "metrics")]
+        self.metrics
+            .record_sequence_cache_lookup(sequence.is_some());
+
+        sequence
+    }
+
+    // Store a Sequence just fetched from, or written to, the network in the local CRDT replica
+    async fn cache_sequence(&self, sequence: Sequence) {
+        if self.sequence_cache_ttl == Duration::from_secs(0) {
+            return;
+        }
+        let now = self.clock.now();
+        let _ = self
+            .sequence_cache
+            .lock()
+            .await
+            .put(*sequence.address(), (sequence, now));
+    }
+
+    // Apply an op made locally to the Sequence in the local CRDT replica, which doesn't
+    // make it any more up to date with the network
+    async fn update_cached_sequence(&self, sequence: Sequence) {
+        if self.sequence_cache_ttl == Duration::from_secs(0) {
+            return;
+        }
+        let mut cache = self.sequence_cache.lock().await;
+        let fetched_at = match cache.get(sequence.address()) {
+            Some((_, fetched_at)) => *fetched_at,
+            None => self.clock.now(),
+        };
+        let _ = cache.put(*sequence.address(), (sequence, fetched_at));
+    }
+
+    //----------------------
+    // Ownership
+    //---------------------
+
+    /// Update sequence data owners
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// use threshold_crypto::SecretKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let new_owner = PublicKey::from( SecretKey::random().public_key() );
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// // The returned address can then be used to `append` data to.
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// let mut sequence = client.get_sequence(address).await?;
+    /// let write_op = sequence.set_owner(new_owner);
+    /// let _ = client.set_sequence_owner(write_op).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn set_sequence_owner(
+        &mut self,
+        op: SequenceWriteOp<SequenceOwner>,
+    ) -> Result<(), CoreError> {
+        let write = SequenceWrite::SetOwner(op);
+        if self.skip_sequence_write_in_dry_run(&write)? {
+            return Ok(());
+        }
+
+        // --------------------------
+        // Payment for PUT
+        // --------------------------
+        let payment_proof = self.create_write_payment_proof().await?;
+
+        //---------------------------------
+        // The _actual_ message
+        //---------------------------------
+        let msg_contents = wrap_seq_write(write, payment_proof);
+        let message = Self::create_cmd_message(msg_contents);
+        let _ = self.connection_manager.send_cmd(&message).await?;
+
+        Ok(())
+    }
+
+    /// Get the current owner of a Sequence.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// let owner = client.get_sequence_owner(address).await?;
+    /// assert_eq!(owner.public_key, client.public_key().await);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_owner(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<SequenceOwner, CoreError> {
+        trace!("Get owner of the Sequence Data at {:?}", address.name());
+
+        // TODO: perhaps we want to grab it directly from the network and update local replica
+        let sequence = self.get_sequence(address).await?;
+        let owner = sequence.owner(sequence.owners_index() - 1).ok_or_else(|| {
+            CoreError::from("Unexpectedly failed to obtain current owner of Sequence")
+        })?;
+
+        Ok(*owner)
+    }
+
+    /// Get the full ownership history of a Sequence, oldest owner first.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// let history = client.get_sequence_owner_history(address).await?;
+    /// assert_eq!(history.len(), 1);
+    /// assert_eq!(history[0].public_key, owner);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_owner_history(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<Vec<SequenceOwner>, CoreError> {
+        trace!(
+            "Get ownership history of the Sequence Data at {:?}",
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        sequence_owners(&sequence)
+    }
+
+    /// Set the new owner of a Sequence Data
+    #[allow(dead_code)]
+    async fn sequence_set_owner(
+        &mut self,
+        address: SequenceAddress,
+        owner: PublicKey,
+    ) -> Result<(), CoreError> {
+        // First we fetch it either from local CRDT replica or from the network if not found
+        let mut sequence = self.get_sequence(address).await?;
+
+        // We do a permissions check just to make sure it won't fail when the operation
+        // is broadcasted to the network, assuming our replica is in sync and up to date
+        // with the ownership information compared with the replicas on the network.
+        sequence
+            .check_permission(SequenceAction::ManagePermissions, self.public_key_sync())
+            .map_err(map_sequence_access_error(SequenceAction::ManagePermissions))?;
+
+        // We can now set the new owner to the Sequence
+        let op = sequence.set_owner(owner);
+
+        // Update the local Sequence CRDT replica
+        self.update_cached_sequence(sequence.clone()).await;
+
+        // Finally we can send the mutation to the network's replicas
+        self.set_sequence_owner(op).await
+    }
+
+    //----------------------
+    // Permissions
+    //---------------------
+
+    /// Mutate sequenced data private permissions
+    /// Wraps msg_contents for payment validation and mutation
+    pub async fn edit_sequence_private_perms(
+        &mut self,
+        op: SequenceWriteOp<SequencePrivatePermissions>,
+    ) -> Result<(), CoreError> {
+        let write = SequenceWrite::SetPrivatePermissions(op);
+        if self.skip_sequence_write_in_dry_run(&write)? {
+            return Ok(());
+        }
+
+        // --------------------------
+        // Payment for PUT
+        // --------------------------
+        let payment_proof = self.create_write_payment_proof().await?;
+
+        //---------------------------------
+        // The _actual_ message
+        //---------------------------------
+        let msg_contents = wrap_seq_write(write, payment_proof);
+        let message = Self::create_cmd_message(msg_contents);
+        let _ = self.connection_manager.send_cmd(&message).await?;
+
+        Ok(())
+    }
+
+    /// Mutate sequenced data public permissions
+    /// Wraps msg_contents for payment validation and mutation
+    pub async fn edit_sequence_public_perms(
+        &mut self,
+        op: SequenceWriteOp<SequencePublicPermissions>,
+    ) -> Result<(), CoreError> {
+        let write = SequenceWrite::SetPublicPermissions(op);
+        if self.skip_sequence_write_in_dry_run(&write)? {
+            return Ok(());
+        }
+
+        // --------------------------
+        // Payment for PUT
+        // --------------------------
+        let payment_proof = self.create_write_payment_proof().await?;
+
+        //---------------------------------
+        // The _actual_ message
+        //---------------------------------
+        let msg_contents = wrap_seq_write(write, payment_proof);
+        let message = Self::create_cmd_message(msg_contents);
+        let _ = self.connection_manager.send_cmd(&message).await?;
+
+        Ok(())
+    }
+
+    /// Get the current set of Permissions of a Public Sequence.
+    ///
+    /// Fails with `CoreError::WrongSequenceScope`, without querying the network, should
+    /// the address be of a Private Sequence.
+    pub async fn get_sequence_pub_permissions(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<SequencePublicPermissions, CoreError> {
+        trace!(
+            "Get permissions from Public Sequence Data at {:?}",
+            address.name()
+        );
+        check_sequence_scope(address, Scope::Public)?;
+
+        // TODO: perhaps we want to grab it directly from the network and update local replica
+        let sequence = self.get_sequence(address).await?;
+        let perms = sequence
+            .pub_permissions(sequence.permissions_index() - 1)
+            .map_err(CoreError::from)?;
+
+        Ok(perms.clone())
+    }
+
+    /// Get the current set of Permissions of a Private Sequence.
+    ///
+    /// Fails with `CoreError::WrongSequenceScope`, without querying the network, should
+    /// the address be of a Public Sequence.
+    pub async fn get_sequence_private_permissions(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<SequencePrivatePermissions, CoreError> {
+        trace!(
+            "Get permissions from Private Sequence Data at {:?}",
+            address.name()
+        );
+        check_sequence_scope(address, Scope::Private)?;
+
+        // TODO: perhaps we want to grab it directly from the network and update local replica
+        let sequence = self.get_sequence(address).await?;
+        let perms = sequence
+            .private_permissions(sequence.permissions_index() - 1)
+            .map_err(CoreError::from)?;
+
+        Ok(perms.clone())
+    }
+
+    /// Get the set of Permissions of a Public Sequence at a specific permissions index.
+    ///
+    /// Returns an error if there is no set of Permissions at that index.
+    pub async fn get_sequence_pub_permissions_at(
+        &mut self,
+        address: SequenceAddress,
+        index: u64,
+    ) -> Result<SequencePublicPermissions, CoreError> {
+        trace!(
+            "Get permissions at index {} from Public Sequence Data at {:?}",
+            index,
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        let perms = sequence.pub_permissions(index).map_err(CoreError::from)?;
+
+        Ok(perms.clone())
+    }
+
+    /// Get the set of Permissions of a Private Sequence at a specific permissions index.
+    ///
+    /// Returns an error if there is no set of Permissions at that index.
+    pub async fn get_sequence_private_permissions_at(
+        &mut self,
+        address: SequenceAddress,
+        index: u64,
+    ) -> Result<SequencePrivatePermissions, CoreError> {
+        trace!(
+            "Get permissions at index {} from Private Sequence Data at {:?}",
+            index,
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        let perms = sequence
+            .private_permissions(index)
+            .map_err(CoreError::from)?;
+
+        Ok(perms.clone())
+    }
+
+    /// Get every set of Permissions a Sequence has had, oldest first.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    ///
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// let history = client.get_sequence_permissions_history(address).await?;
+    /// assert_eq!(history.len(), 1);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_permissions_history(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<Vec<SequencePermissions>, CoreError> {
+        trace!(
+            "Get permissions history from Sequence Data at {:?}",
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        sequence_permissions(&sequence)
+    }
+
+    /// Get the current set of Permissions for a specific user in a Sequence.
+    ///
+    /// On a Public Sequence the user can be `SequenceUser::Anyone`, to get the permissions
+    /// granted to everyone. Private Sequences only grant permissions to keys. Fails with
+    /// `CoreError::NoSuchUserPermissions` should the user have no permissions entry at all.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, SequenceAction, SequenceUser, SequenceUserPermissions};
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let owner = client.public_key().await;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// match client.get_sequence_user_permissions(address, SequenceUser::Key(owner)).await? {
+    ///     SequenceUserPermissions::Priv(perms) => assert!(perms.is_allowed(SequenceAction::Append)),
+    ///     _ => panic!("Private Sequence with public permissions"),
+    /// }
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn get_sequence_user_permissions(
+        &mut self,
+        address: SequenceAddress,
+        user: SequenceUser,
+    ) -> Result<SequenceUserPermissions, CoreError> {
+        trace!(
+            "Get permissions for user {:?} from Sequence Data at {:?}",
+            user,
+            address.name()
+        );
+
+        // TODO: perhaps we want to grab it directly from the network and update local replica
+        let sequence = self.get_sequence(address).await?;
+        let index = sequence.permissions_index() - 1;
+        let perms = if sequence.is_pub() {
+            sequence
+                .pub_permissions(index)?
+                .permissions
+                .get(&user)
+                .map(|perms| SequenceUserPermissions::Public(*perms))
+        } else {
+            match user {
+                SequenceUser::Key(key) => sequence
+                    .private_permissions(index)?
+                    .permissions
+                    .get(&key)
+                    .map(|perms| SequenceUserPermissions::Priv(*perms)),
+                SequenceUser::Anyone => None,
+            }
+        };
+
+        perms.ok_or_else(|| CoreError::NoSuchUserPermissions { user })
+    }
+
+    /// Check whether a user would be allowed to append to a Sequence, e.g. before granting
+    /// them access, as per its current permissions and owner.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, PublicKey};
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let collaborator = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// assert!(!client.can_user_append(address, collaborator).await?);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn can_user_append(
+        &mut self,
+        address: SequenceAddress,
+        user: PublicKey,
+    ) -> Result<bool, CoreError> {
+        trace!(
+            "Check whether {:?} can append to Sequence Data at {:?}",
+            user,
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        Ok(sequence
+            .check_permission(SequenceAction::Append, user)
+            .is_ok())
+    }
+
+    /// Check whether a user would be allowed to read a Sequence, as per its current permissions
+    /// and owner. Anyone can read a Public Sequence.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, PublicKey, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let owner = client.public_key().await;
+    /// let other = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    /// let address = client.store_private_sequence(None, XorName::random(), 10, owner, perms).await?;
+    ///
+    /// assert!(client.can_user_read(address, owner).await?);
+    /// assert!(!client.can_user_read(address, other).await?);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn can_user_read(
+        &mut self,
+        address: SequenceAddress,
+        user: PublicKey,
+    ) -> Result<bool, CoreError> {
+        trace!(
+            "Check whether {:?} can read Sequence Data at {:?}",
+            user,
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        Ok(sequence
+            .check_permission(SequenceAction::Read, user)
+            .is_ok())
+    }
+
+    /// Check whether a Sequence is frozen, i.e. no user, `SequenceUser::Anyone` included, is
+    /// granted the permission to append to it by its current permissions.
+    ///
+    /// The owner isn't bound by the permissions, so may still append to a frozen Sequence.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_core::client::sequence_apis::anyone_read_only;
+    /// use safe_nd::Money;
+    /// use xor_name::XorName;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = threshold_crypto::SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let owner = client.public_key().await;
+    /// let address = client.store_public_sequence(None, XorName::random(), 10, owner, anyone_read_only()).await?;
+    ///
+    /// assert!(client.is_sequence_frozen(address).await?);
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn is_sequence_frozen(
+        &mut self,
+        address: SequenceAddress,
+    ) -> Result<bool, CoreError> {
+        trace!(
+            "Check whether Sequence Data at {:?} is frozen",
+            address.name()
+        );
+
+        let sequence = self.get_sequence(address).await?;
+        let index = sequence.permissions_index() - 1;
+        let can_append = if sequence.is_pub() {
+            sequence
+                .pub_permissions(index)?
+                .permissions
+                .values()
+                .any(|perms| perms.is_allowed(SequenceAction::Append) == Some(true))
+        } else {
+            sequence
+                .private_permissions(index)?
+                .permissions
+                .values()
+                .any(|perms| perms.is_allowed(SequenceAction::Append))
+        };
+
+        Ok(!can_append)
+    }
+
+    /// Set permissions to Public Sequence Data
+    #[allow(dead_code)]
+    async fn sequence_set_pub_permissions(
+        &mut self,
+        address: SequenceAddress,
+        permissions: BTreeMap<SequenceUser, SequencePubUserPermissions>,
+    ) -> Result<(), CoreError> {
+        // First we fetch it either from local CRDT replica or from the network if not found
+        let mut sequence = self.get_sequence(address).await?;
+
+        // We do a permissions check just to make sure it won't fail when the operation
+        // is broadcasted to the network, assuming our replica is in sync and up to date
+        // with the permissions information compared with the replicas on the network.
+        sequence
+            .check_permission(SequenceAction::ManagePermissions, self.public_key_sync())
+            .map_err(map_sequence_access_error(SequenceAction::ManagePermissions))?;
+
+        // We can now set the new permissions to the Sequence
+        let op = sequence.set_pub_permissions(permissions)?;
+
+        // Update the local Sequence CRDT replica
+        self.update_cached_sequence(sequence.clone()).await;
+
+        // Finally we can send the mutation to the network's replicas
+        self.edit_sequence_public_perms(op).await
+    }
+
+    /// Set permissions to Private Sequence Data
+    #[allow(dead_code)]
+    async fn sequence_set_private_permissions(
+        &mut self,
+        address: SequenceAddress,
+        permissions: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+    ) -> Result<(), CoreError> {
+        // First we fetch it either from local CRDT replica or from the network if not found
+        let mut sequence = self.get_sequence(address).await?;
+
+        // We do a permissions check just to make sure it won't fail when the operation
+        // is broadcasted to the network, assuming our replica is in sync and up to date
+        // with the permissions information compared with the replicas on the network.
+        // TODO: if it fails, try to sync-up perms with rmeote replicas and try once more
+        sequence
+            .check_permission(SequenceAction::ManagePermissions, self.public_key_sync())
+            .map_err(map_sequence_access_error(SequenceAction::ManagePermissions))?;
+
+        // We can now set the new permissions to the Sequence
+        let op = sequence.set_private_permissions(permissions)?;
+
+        // Update the local Sequence CRDT replica
+        self.update_cached_sequence(sequence.clone()).await;
+
+        // Finally we can send the mutation to the network's replicas
+        self.edit_sequence_private_perms(op).await
+    }
+
+    /// Revoke all permissions of a single user from Private Sequence Data
+    ///
+    /// The permissions of every other user are left untouched. Fails with
+    /// `CoreError::NoSuchUserPermissions` should the user have no permissions to revoke.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use std::collections::BTreeMap;
+    /// use xor_name::XorName;
+    /// use threshold_crypto::SecretKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let name = XorName::random();
+    /// let tag = 10;
+    /// let owner = client.public_key().await;
+    /// let user = PublicKey::from( SecretKey::random().public_key() );
+    /// let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+    ///
+    /// // Set the access permissions
+    /// let _ = perms.insert(
+    ///    owner,
+    ///    SequencePrivUserPermissions::new(true, true, true),
+    /// );
+    /// let _ = perms.insert(
+    ///    user,
+    ///    SequencePrivUserPermissions::new(true, true, false),
+    /// );
+    ///
+    /// let address = client.store_private_sequence(None, name, tag, owner, perms).await?;
+    ///
+    /// client.sequence_revoke_user(address, user).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn sequence_revoke_user(
+        &mut self,
+        address: SequenceAddress,
+        user: PublicKey,
+    ) -> Result<(), CoreError> {
+        trace!(
+            "Revoke permissions of user {:?} from Private Sequence Data at {:?}",
+            user,
+            address.name()
+        );
+
+        let mut permissions = self
+            .get_sequence_private_permissions(address)
+            .await?
+            .permissions;
+
+        if permissions.remove(&user).is_none() {
+            return Err(CoreError::NoSuchUserPermissions {
+                user: SequenceUser::Key(user),
+            });
+        }
+
+        self.sequence_set_private_permissions(address, permissions)
+            .await
+    }
+
+    /// Grant permissions to several users of Private Sequence Data at once
+    ///
+    /// The grants are merged into the current permissions, in a single write: a user already
+    /// in the permissions has them replaced by their grant, and the permissions of every user
+    /// not in `grants` are left untouched.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError; use std::str::FromStr;
+    /// use safe_core::Client;
+    /// use safe_core::client::sequence_apis::private_perms_for;
+    /// use safe_nd::{PublicKey, Money, SequencePrivUserPermissions};
+    /// use xor_name::XorName;
+    /// use threshold_crypto::SecretKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async { let secret_key = SecretKey::random();
+    /// // Let's use an existing client, with a pre-existing balance to be used for write payments.
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    /// # let initial_balance = Money::from_str("100")?; client.trigger_simulated_farming_payout(initial_balance).await?;
+    /// let address = client.store_private_sequence_owner_only(None, XorName::random(), 10).await?;
+    ///
+    /// let team: Vec<_> = (0..3).map(|_| PublicKey::from(SecretKey::random().public_key())).collect();
+    /// let grants = private_perms_for(&team, SequencePrivUserPermissions::new(true, true, false));
+    /// client.grant_sequence_users(address, grants).await?;
+    /// # let balance_after_write = client.get_local_balance().await; assert_ne!(initial_balance, balance_after_write); Ok(()) } ); }
+    /// ```
+    pub async fn grant_sequence_users(
+        &mut self,
+        address: SequenceAddress,
+        grants: BTreeMap<PublicKey, SequencePrivUserPermissions>,
+    ) -> Result<(), CoreError> {
+        trace!(
+            "Grant permissions to {} users of Private Sequence Data at {:?}",
+            grants.len(),
+            address.name()
+        );
+
+        let mut permissions = self
+            .get_sequence_private_permissions(address)
+            .await?
+            .permissions;
+        permissions.extend(grants);
+
+        self.sequence_set_private_permissions(address, permissions)
+            .await
+    }
+
+    /// Commit the operations of a transaction to a Sequence, in order.
+    ///
+    /// The network has no support for several operations in a single write, so each operation
+    /// is still sent and paid for on its own. To get as close as possible to all-or-nothing,
+    /// the operations are first all applied to the Sequence as freshly fetched from the network,
+    /// checking the permissions for each of them in turn, and the balance is checked to cover
+    /// them all. Should any check fail, nothing is sent nor paid for.
+    ///
+    /// Should sending one of them fail after that, the ones already sent are kept by the
+    /// network and it fails with `CoreError::TransactionPartiallyApplied`, telling how many were.
+    pub async fn commit_sequence_transaction(
+        &mut self,
+        address: SequenceAddress,
+        transaction: SequenceTransaction,
+    ) -> Result<(), CoreError> {
+        trace!(
+            "Commit transaction of {} operations to Sequence Data at {:?}",
+            transaction.len(),
+            address.name()
+        );
+        self.check_writes_allowed()?;
+        if transaction.is_empty() {
+            return Ok(());
+        }
+
+        // Apply them all locally first, any check failing before anything is sent
+        let mut sequence = self.get_sequence_with_opts(address, true).await?;
+        let requester = self.public_key_sync();
+        let mut writes = Vec::with_capacity(transaction.len());
+        for op in transaction.ops {
+            let action = match op {
+                SequenceTransactionOp::Append(_) => SequenceAction::Append,
+                _ => SequenceAction::ManagePermissions,
+            };
+            sequence
+                .check_permission(action, requester)
+                .map_err(map_sequence_access_error(action))?;
+
+            let write = match op {
+                SequenceTransactionOp::Append(entry) => {
+                    SequenceTransactionWrite::Append(sequence.append(entry))
+                }
+                SequenceTransactionOp::SetOwner(owner) => {
+                    SequenceTransactionWrite::SetOwner(sequence.set_owner(owner))
+                }
+                SequenceTransactionOp::SetPubPermissions(permissions) => {
+                    SequenceTransactionWrite::SetPubPermissions(
+                        sequence.set_pub_permissions(permissions)?,
+                    )
+                }
+                SequenceTransactionOp::SetPrivatePermissions(permissions) => {
+                    SequenceTransactionWrite::SetPrivatePermissions(
+                        sequence.set_private_permissions(permissions)?,
+                    )
+                }
+            };
+            writes.push(write);
+        }
+
+        if !self.can_afford_writes(writes.len() as u64).await? {
+            return Err(CoreError::PaymentFailed {
+                kind: PaymentFailureKind::InsufficientBalance,
+            });
+        }
+
+        let count = writes.len();
+        let mut appends = Vec::new();
+        for (applied, write) in writes.into_iter().enumerate() {
+            let res = match write {
+                SequenceTransactionWrite::Append(op) => {
+                    appends.push(op.clone());
+                    self.pay_and_write_append_to_sequence_to_network(op).await
+                }
+                SequenceTransactionWrite::SetOwner(op) => self.set_sequence_owner(op).await,
+                SequenceTransactionWrite::SetPubPermissions(op) => {
+                    self.edit_sequence_public_perms(op).await
+                }
+                SequenceTransactionWrite::SetPrivatePermissions(op) => {
+                    self.edit_sequence_private_perms(op).await
+                }
+            };
+
+            if let Err(error) = res {
+                warn!(
+                    "Transaction on Sequence at {:?} failed after {} of {} operations: {}",
+                    address.name(),
+                    applied,
+                    count,
+                    error
+                );
+                // The local replica has them all, which the network doesn't
+                self.invalidate_sequence_cache(address).await;
+                return Err(CoreError::TransactionPartiallyApplied {
+                    applied,
+                    reason: error.to_string(),
+                });
+            }
+        }
+
+        self.update_cached_sequence(sequence).await;
+        // Keep the appends to be merged into the Sequence fetched from the network,
+        // until the network's replicas have them
+        if !self.dry_run {
+            self.pending_sequence_ops
+                .lock()
+                .await
+                .entry(address)
+                .or_default()
+                .extend(appends);
+        }
+
+        Ok(())
+    }
+}
+
+#[allow(missing_docs)]
+#[cfg(any(test, feature = "simulated-payouts", feature = "testing"))]
+pub mod exported_tests {
+    use super::*;
+    use crate::client::{ClientConfig, Clock, SEQUENCE_DELETED_CHECK_INTERVAL};
+    use crate::config_handler::Config;
+    use crate::connection_manager::ConnectionManager;
+    use crate::utils::test_utils::{calculate_new_balance, gen_bls_keypair, FlakyTransport};
+    use safe_nd::{Error as SndError, Message, Money, SequencePrivUserPermissions};
+    use serde::Deserialize;
+    use std::str::FromStr;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+    use unwrap::unwrap;
+    use xor_name::XorName;
+
+    // Permissions of a Private Sequence which only its owner can access
+    fn owner_only_perms(owner: PublicKey) -> BTreeMap<PublicKey, SequencePrivUserPermissions> {
+        let mut perms = BTreeMap::new();
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        perms
+    }
+
+    pub async fn sequence_deletions_should_cost_put_price() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let sequence_address = client
+            .store_private_sequence(None, name, tag, owner, perms)
+            .await?;
+
+        let balance_before_delete = client.get_balance().await?;
+        client.delete_sequence(sequence_address).await?;
+        let new_balance = client.get_balance().await?;
+
+        // make sure we have _some_ balance
+        assert_ne!(balance_before_delete, Money::from_str("0")?);
+        assert_ne!(balance_before_delete, new_balance);
+
+        Ok(())
+    }
+
+    pub async fn sequence_deletion_costs_write_price() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let sequence_address = client
+            .store_private_sequence(None, name, tag, owner, perms)
+            .await?;
+
+        let price = client.get_write_price().await?;
+        assert_ne!(price, Money::from_str("0")?);
+
+        let balance_before_delete = client.get_balance().await?;
+        client.delete_sequence(sequence_address).await?;
+        let new_balance = client.get_balance().await?;
+
+        let expected_balance = balance_before_delete
+            .checked_sub(price)
+            .ok_or_else(|| CoreError::from("Invalid checked sub in test"))?;
+        assert_eq!(new_balance, expected_balance);
+
+        Ok(())
+    }
+
+    pub async fn sequence_with_entries_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let perms = owner_only_perms(owner);
+
+        let initial_balance = client.get_balance().await?;
+        let entries = vec![b"VALUE1".to_vec(), b"VALUE2".to_vec(), b"VALUE3".to_vec()];
+        let (address, entries_index) = client
+            .store_private_sequence_with_entries(entries, name, tag, owner, perms)
+            .await?;
+        assert_eq!(entries_index, 3);
+
+        // a single write was paid
+        let expected_balance = calculate_new_balance(initial_balance, Some(1), None);
+        assert_eq!(client.get_balance().await?, expected_balance);
+
+        client.clear_sequence_cache().await;
+        let data = client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await?;
+        assert_eq!(data.len(), 3);
+        assert_eq!(unwrap!(std::str::from_utf8(&data[0])), "VALUE1");
+        assert_eq!(unwrap!(std::str::from_utf8(&data[2])), "VALUE3");
+
+        Ok(())
+    }
+
+    /// Sequence data tests ///
+
+    pub async fn sequence_basics_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+        let owner = client.public_key().await;
+
+        // store a Private Sequence
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+        let sequence = client.get_sequence(address).await?;
+        assert!(sequence.is_private());
+        assert_eq!(*sequence.name(), name);
+        assert_eq!(sequence.tag(), tag);
+        assert_eq!(sequence.permissions_index(), 1);
+        assert_eq!(sequence.owners_index(), 1);
+        assert_eq!(sequence.entries_index(), 0);
+
+        // store a Public Sequence
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, true),
+        );
+        let address = client
+            .store_public_sequence(None, name, tag, owner, perms)
+            .await?;
+        let sequence = client.get_sequence(address).await?;
+        assert!(sequence.is_pub());
+        assert_eq!(*sequence.name(), name);
+        assert_eq!(sequence.tag(), tag);
+        assert_eq!(sequence.permissions_index(), 1);
+        assert_eq!(sequence.owners_index(), 1);
+        assert_eq!(sequence.entries_index(), 0);
+
+        Ok(())
+    }
+
+    pub async fn sequence_exists_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+
+        // a random address shouldn't resolve to any data
+        let random_address = SequenceAddress::Public {
+            name: XorName::random(),
+            tag,
+        };
+        assert!(!client.sequence_exists(random_address).await?);
+        assert!(client
+            .sequence_cache
+            .lock()
+            .await
+            .get(&random_address)
+            .is_none());
+
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+        assert!(client.sequence_exists(address).await?);
+
+        // an append the network's replicas don't have yet is kept in the local replica
+        let (_, op) = client
+            .append_locally(address, b"VALUE1".to_vec(), false, false)
+            .await?;
+        client
+            .pending_sequence_ops
+            .lock()
+            .await
+            .entry(address)
+            .or_default()
+            .push(op);
+        assert!(client.sequence_exists(address).await?);
+        assert_eq!(client.get_sequence(address).await?.entries_index(), 1);
+
+        Ok(())
+    }
+
+    pub async fn diff_sequence_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        // Append an entry to the local replica only, as if its append had been lost
+        let mut sequence = client.get_sequence(address).await?;
+        let _op = sequence.append(b"LOCAL".to_vec());
+        client.update_cached_sequence(sequence).await;
+
+        let diff = client.diff_sequence(address).await?;
+        assert!(!diff.is_empty());
+        assert!(diff.local_only_entries.contains(&(1, b"LOCAL".to_vec())));
+        assert!(diff.remote_only_entries.is_empty());
+        assert!(diff.local_only_owners.is_empty());
+        assert!(diff.remote_only_owners.is_empty());
+
+        // The diff leaves the local replica as it was
+        let cached = client.get_sequence(address).await?;
+        assert_eq!(cached.entries_index(), 2);
+
+        Ok(())
+    }
+
+    pub async fn sequence_scope_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let tag = 15000;
+
+        let private_address = client
+            .store_private_sequence_owner_only(None, XorName::random(), tag)
+            .await?;
+        assert_eq!(
+            client.sequence_scope(private_address).await?,
+            Scope::Private
+        );
+
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Key(owner),
+            SequencePubUserPermissions::new(true, true),
+        );
+        let public_address = client
+            .store_public_sequence(None, XorName::random(), tag, owner, perms)
+            .await?;
+        assert_eq!(client.sequence_scope(public_address).await?, Scope::Public);
+
+        Ok(())
+    }
+
+    pub async fn sequence_cache_eviction_test() -> Result<(), CoreError> {
+        let config = ClientConfig {
+            sequence_cache_capacity: 1,
+            ..Default::default()
+        };
+        let mut client = Client::with_config(None, config).await?;
+        let owner = client.public_key().await;
+
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, true),
+        );
+        let first_address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms.clone())
+            .await?;
+        let second_address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        // Only the last one stored is still cached
+        assert!(!client.sequence_cache.lock().await.contains(&first_address));
+        assert!(client.sequence_cache.lock().await.contains(&second_address));
+
+        let queries_sent = client.connection_manager.queries_sent();
+        let sequence = client.get_sequence(first_address).await?;
+        assert_eq!(*sequence.address(), first_address);
+        assert!(client.connection_manager.queries_sent() > queries_sent);
+
+        Ok(())
+    }
+
+    // A clock only moving forward when told to
+    struct MockClock {
+        now: Mutex<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            Self {
+                now: Mutex::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, duration: Duration) {
+            let mut now = unwrap!(self.now.lock());
+            *now += duration;
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            *unwrap!(self.now.lock())
+        }
+    }
+
+    // Create a client with the given TTL and a mock clock, storing a Sequence
+    async fn client_with_cached_sequence(
+        ttl: Duration,
+    ) -> Result<(Client, Arc<MockClock>, SequenceAddress), CoreError> {
+        let config = ClientConfig {
+            sequence_cache_ttl: ttl,
+            ..Default::default()
+        };
+        let mut client = Client::with_config(None, config).await?;
+        let clock = Arc::new(MockClock::new());
+        client.clock = clock.clone();
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        Ok((client, clock, address))
+    }
+
+    pub async fn sequence_cache_fresh_hit_test() -> Result<(), CoreError> {
+        let ttl = Duration::from_secs(60);
+        let (mut client, clock, address) = client_with_cached_sequence(ttl).await?;
+
+        clock.advance(ttl / 2);
+        let queries_sent = client.connection_manager.queries_sent();
+        let sequence = client.get_sequence(address).await?;
+        assert_eq!(*sequence.address(), address);
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent);
+
+        Ok(())
+    }
+
+    pub async fn sequence_no_cache_on_write_test() -> Result<(), CoreError> {
+        let config = ClientConfig {
+            cache_on_write: false,
+            ..Default::default()
+        };
+        let mut client = Client::with_config(None, config).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        assert!(!client.sequence_cache.lock().await.contains(&address));
+
+        // Fetched from the network on the first read, then read from the local replica
+        let queries_sent = client.connection_manager.queries_sent();
+        let _ = client.get_sequence(address).await?;
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent + 1);
+        assert!(client.sequence_cache.lock().await.contains(&address));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "metrics")]
+    pub async fn sequence_cache_metrics_test() -> Result<(), CoreError> {
+        let (mut client, _clock, address) =
+            client_with_cached_sequence(Duration::from_secs(60)).await?;
+
+        let before = client.metrics_snapshot();
+        let _ = client.get_sequence(address).await?;
+        let after_hit = client.metrics_snapshot();
+        assert_eq!(
+            after_hit.sequence_cache_hits,
+            before.sequence_cache_hits + 1
+        );
+        assert_eq!(
+            after_hit.sequence_cache_misses,
+            before.sequence_cache_misses
+        );
+
+        client.invalidate_sequence_cache(address).await;
+        let _ = client.get_sequence(address).await?;
+        let after_miss = client.metrics_snapshot();
+        assert_eq!(
+            after_miss.sequence_cache_hits,
+            after_hit.sequence_cache_hits
+        );
+        assert_eq!(
+            after_miss.sequence_cache_misses,
+            after_hit.sequence_cache_misses + 1
+        );
+        assert!(after_miss.queries_sent > after_hit.queries_sent);
+        assert!(after_miss.query_responses.contains_key("GetSequence"));
+
+        Ok(())
+    }
+
+    pub async fn sequence_cache_expired_refetch_test() -> Result<(), CoreError> {
+        let ttl = Duration::from_secs(60);
+        let (mut client, clock, address) = client_with_cached_sequence(ttl).await?;
+
+        clock.advance(ttl);
+        let queries_sent = client.connection_manager.queries_sent();
+        let sequence = client.get_sequence(address).await?;
+        assert_eq!(*sequence.address(), address);
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent + 1);
+
+        // Fetching it again made it fresh
+        let _ = client.get_sequence(address).await?;
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent + 1);
+
+        Ok(())
+    }
+
+    pub async fn sequence_cache_zero_ttl_test() -> Result<(), CoreError> {
+        let (mut client, _clock, address) =
+            client_with_cached_sequence(Duration::from_secs(0)).await?;
+        assert!(!client.sequence_cache.lock().await.contains(&address));
+
+        let queries_sent = client.connection_manager.queries_sent();
+        let _ = client.get_sequence(address).await?;
+        let _ = client.get_sequence(address).await?;
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent + 2);
+
+        Ok(())
+    }
+
+    pub async fn concurrent_appends_from_two_clients_test() -> Result<(), CoreError> {
+        let mut client_1 = Client::new(None).await?;
+        let mut client_2 = Client::new(None).await?;
+        let owner = client_1.public_key().await;
+
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, true),
+        );
+        let address = client_1
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        // Both clients append from the same view of the Sequence
+        let _ = client_2.get_sequence(address).await?;
+        client_1
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+        client_2
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+
+        for client in &mut [client_1, client_2] {
+            let sequence = client.get_sequence_with_opts(address, true).await?;
+            assert_eq!(sequence.entries_index(), 2);
+
+            let entries =
+                unwrap!(sequence.in_range(SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)));
+            assert!(entries.contains(&b"VALUE1".to_vec()));
+            assert!(entries.contains(&b"VALUE2".to_vec()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn append_if_index_test() -> Result<(), CoreError> {
+        let mut client_1 = Client::new(None).await?;
+        let mut client_2 = Client::new(None).await?;
+        let owner = client_1.public_key().await;
+
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, true),
+        );
+        let address = client_1
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        // Both clients expect to append the first entry
+        let _ = client_2.get_sequence(address).await?;
+        client_1
+            .append_if_index(address, 0, b"VALUE1".to_vec())
+            .await?;
+        match client_2
+            .append_if_index(address, 0, b"VALUE2".to_vec())
+            .await
+        {
+            Err(CoreError::ConcurrentModification { actual }) => assert_eq!(actual, 1),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        // With an up to date expectation it goes through
+        client_2
+            .append_if_index(address, 1, b"VALUE2".to_vec())
+            .await?;
+        let sequence = client_1.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 2);
+
+        Ok(())
+    }
+
+    pub async fn await_sequence_entry_confirmed_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let index = client
+            .append_to_sequence_indexed(address, b"VALUE1".to_vec())
+            .await?;
+        client
+            .await_sequence_entry_confirmed(address, index, Duration::from_secs(10))
+            .await?;
+
+        // The network has it, regardless of the local replica
+        client.clear_sequence_cache().await;
+        let (last_index, last_entry) = client.get_sequence_last_entry(address).await?;
+        assert_eq!(last_index, index);
+        assert_eq!(last_entry, b"VALUE1".to_vec());
+
+        // An entry never appended is never confirmed
+        match client
+            .await_sequence_entry_confirmed(address, index + 1, Duration::from_millis(500))
+            .await
+        {
+            Err(CoreError::RequestTimeout) => (),
+            res => panic!(
+                "Unexpected result awaiting an entry never appended: {:?}",
+                res
+            ),
+        }
+
+        Ok(())
+    }
+
+    pub async fn prefetch_sequences_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let mut addresses = Vec::new();
+        for _ in 0..3 {
+            let address = client
+                .store_private_sequence_owner_only(None, XorName::random(), 15000)
+                .await?;
+            addresses.push(address);
+        }
+        client.clear_sequence_cache().await;
+
+        let results = client.prefetch_sequences(addresses.clone()).await;
+        assert_eq!(results.len(), 3);
+        for res in results {
+            res?;
+        }
+
+        // They're all read from the local replica now
+        let queries_sent = client.connection_manager.queries_sent();
+        for address in addresses {
+            let _ = client.get_sequence(address).await?;
+        }
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent);
+
+        // A Sequence which doesn't exist fails on its own
+        let missing = SequenceAddress::Private {
+            name: XorName::random(),
+            tag: 15000,
+        };
+        let results = client.prefetch_sequences(vec![missing]).await;
+        assert!(results[0].is_err());
+
+        Ok(())
+    }
+
+    pub async fn get_multi_sequence_ranges_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let first = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        let second = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        for value in &[b"VALUE1", b"VALUE2", b"VALUE3"] {
+            client.append_to_sequence(first, value.to_vec()).await?;
+        }
+        for value in &[b"OTHER1", b"OTHER2"] {
+            client.append_to_sequence(second, value.to_vec()).await?;
+        }
+
+        let results = client
+            .get_multi_sequence_ranges(vec![
+                (
+                    first,
+                    (SequenceIndex::FromStart(1), SequenceIndex::FromEnd(0)),
+                ),
+                (
+                    second,
+                    (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+                ),
+            ])
+            .await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref().ok(),
+            Some(&vec![b"VALUE2".to_vec(), b"VALUE3".to_vec()])
+        );
+        assert_eq!(
+            results[1].as_ref().ok(),
+            Some(&vec![b"OTHER1".to_vec(), b"OTHER2".to_vec()])
+        );
+
+        Ok(())
+    }
+
+    pub async fn concurrent_sequence_reads_are_coalesced_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        client.clear_sequence_cache().await;
+
+        let queries_sent = client.connection_manager.queries_sent();
+        let reads = (0..5).map(|_| {
+            let mut client = client.clone();
+            async move { client.get_sequence(address).await }
+        });
+        for res in futures::future::join_all(reads).await {
+            assert_eq!(*res?.address(), address);
+        }
+
+        // All readers got the Sequence from a single query
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent + 1);
+        assert!(client
+            .in_flight_sequence_fetches
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty());
+
+        Ok(())
+    }
+
+    pub async fn get_sequence_timeout_cached_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        // Read from the local replica, so no time is needed at all
+        let sequence = client
+            .get_sequence_timeout(address, Duration::from_millis(0))
+            .await?;
+        assert_eq!(*sequence.address(), address);
+
+        // Read from the network otherwise
+        client.clear_sequence_cache().await;
+        let sequence = client
+            .get_sequence_timeout(address, Duration::from_secs(10))
+            .await?;
+        assert_eq!(*sequence.address(), address);
+
+        Ok(())
+    }
+
+    pub async fn get_sequence_entry_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        for value in &[b"VALUE1", b"VALUE2", b"VALUE3"] {
+            client.append_to_sequence(address, value.to_vec()).await?;
+        }
+
+        assert_eq!(
+            client.get_sequence_entry(address, 1).await?,
+            b"VALUE2".to_vec()
+        );
+        match client.get_sequence_entry(address, 3).await {
+            Err(CoreError::DataError(SndError::NoSuchEntry)) => (),
+            res => panic!("Unexpected result reading past the last entry: {:?}", res),
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_sequence_entries_indexed_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        assert!(client
+            .get_sequence_entries_indexed(address)
+            .await?
+            .is_empty());
+
+        for value in &[b"VALUE1", b"VALUE2", b"VALUE3"] {
+            client.append_to_sequence(address, value.to_vec()).await?;
+        }
+
+        assert_eq!(
+            client.get_sequence_entries_indexed(address).await?,
+            vec![
+                (0, b"VALUE1".to_vec()),
+                (1, b"VALUE2".to_vec()),
+                (2, b"VALUE3".to_vec()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    pub async fn encrypted_private_sequence_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+
+        let perms = owner_only_perms(owner);
+        let key = crate::utils::generate_sym_enc_key();
+        let address = client
+            .store_encrypted_private_sequence(
+                Some(vec![b"VALUE1".to_vec()]),
+                XorName::random(),
+                15000,
+                owner,
+                perms,
+                &key,
+            )
+            .await?;
+        client
+            .append_to_encrypted_sequence(address, b"VALUE2".to_vec(), &key)
+            .await?;
+
+        let range = (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0));
+        let raw_entries = client.get_sequence_range(address, range).await?;
+        assert_eq!(raw_entries.len(), 2);
+        assert_ne!(raw_entries[0], b"VALUE1".to_vec());
+        assert_ne!(raw_entries[1], b"VALUE2".to_vec());
+
+        assert_eq!(
+            client
+                .get_encrypted_sequence_range(address, range, &key)
+                .await?,
+            vec![b"VALUE1".to_vec(), b"VALUE2".to_vec()]
+        );
+
+        let wrong_key = crate::utils::generate_sym_enc_key();
+        match client
+            .get_encrypted_sequence_range(address, range, &wrong_key)
+            .await
+        {
+            Err(CoreError::SymmetricDecipherFailure) => (),
+            res => panic!("Unexpected result decrypting with another key: {:?}", res),
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_sequence_range_validation_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        // Empty Sequence
+        match client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await
+        {
+            Err(CoreError::InvalidRange { .. }) => (),
+            res => panic!("Unexpected result for an empty Sequence: {:?}", res),
+        }
+
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+
+        // Inverted range, with the end resolved from the end of the Sequence
+        match client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromStart(2), SequenceIndex::FromEnd(1)),
+            )
+            .await
+        {
+            Err(CoreError::InvalidRange { .. }) => (),
+            res => panic!("Unexpected result for an inverted range: {:?}", res),
+        }
+
+        // Out of bounds start
+        match client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromStart(3), SequenceIndex::FromEnd(0)),
+            )
+            .await
+        {
+            Err(CoreError::InvalidRange { .. }) => (),
+            res => panic!("Unexpected result for an out of bounds start: {:?}", res),
+        }
+
+        let entries = client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromEnd(2), SequenceIndex::FromEnd(1)),
+            )
+            .await?;
+        assert_eq!(entries, vec![b"VALUE1".to_vec()]);
+
+        Ok(())
+    }
+
+    pub async fn get_sequence_at_version_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let entries: Vec<SequenceEntry> =
+            (0..5).map(|i| format!("VALUE{}", i).into_bytes()).collect();
+        for entry in &entries {
+            client.append_to_sequence(address, entry.clone()).await?;
+        }
+
+        let at_version_2 = client.get_sequence_at_version(address, 2).await?;
+        assert_eq!(at_version_2, entries[..3].to_vec());
+
+        let at_latest = client.get_sequence_at_version(address, 4).await?;
+        assert_eq!(at_latest, entries);
+
+        match client.get_sequence_at_version(address, 5).await {
+            Err(CoreError::InvalidRange { .. }) => Ok(()),
+            res => panic!("Unexpected result for a future version: {:?}", res),
+        }
+    }
+
+    pub async fn sequence_writes_fail_on_insufficient_balance_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+
+        let perms = owner_only_perms(owner);
+        let address = client
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms.clone())
+            .await?;
+
+        // Spend all that's left
+        let balance = client.get_local_balance().await;
+        client
+            .send_money(gen_bls_keypair().public_key(), balance)
+            .await?;
+
+        let is_insufficient_balance = |res: Result<(), CoreError>| match res {
+            Err(CoreError::PaymentFailed {
+                kind: PaymentFailureKind::InsufficientBalance,
+            }) => true,
+            res => panic!("Unexpected result writing without balance: {:?}", res),
+        };
+        assert!(is_insufficient_balance(
+            client
+                .store_private_sequence(None, XorName::random(), 15000, owner, perms)
+                .await
+                .map(|_| ())
+        ));
+        assert!(is_insufficient_balance(
+            client.append_to_sequence(address, b"VALUE".to_vec()).await
+        ));
+        assert!(is_insufficient_balance(
+            client.delete_sequence(address).await
+        ));
+
+        Ok(())
+    }
+
+    pub async fn append_entry_size_limit_test() -> Result<(), CoreError> {
+        let config = ClientConfig {
+            max_entry_size: 16,
+            ..Default::default()
+        };
+        let mut client = Client::with_config(None, config).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        client.append_to_sequence(address, vec![0; 16]).await?;
+
+        // Rejected before paying for it
+        let balance = client.get_local_balance().await;
+        match client.append_to_sequence(address, vec![0; 17]).await {
+            Err(CoreError::EntryTooLarge { size: 17, max: 16 }) => (),
+            res => panic!("Unexpected result appending an oversized entry: {:?}", res),
+        }
+        assert_eq!(client.get_local_balance().await, balance);
+        assert_eq!(client.get_sequence(address).await?.entries_index(), 1);
+
+        Ok(())
+    }
+
+    pub async fn append_to_sequence_indexed_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let index = client
+            .append_to_sequence_indexed(address, b"VALUE1".to_vec())
+            .await?;
+        assert_eq!(index, 0);
+        let index = client
+            .append_to_sequence_indexed(address, b"VALUE2".to_vec())
+            .await?;
+        assert_eq!(index, 1);
+
+        let (last_index, last_entry) = client.get_sequence_last_entry(address).await?;
+        assert_eq!(last_index, 1);
+        assert_eq!(last_entry, b"VALUE2".to_vec());
+
+        Ok(())
+    }
+
+    pub async fn append_to_sequence_nowait_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        for i in 0..5 {
+            let index = client
+                .append_to_sequence_nowait(address, format!("VALUE{}", i).into_bytes())
+                .await?;
+            assert_eq!(index, i);
+        }
+
+        client.flush_background_writes().await;
+        assert!(client.drain_background_errors().is_empty());
+
+        let entries = client
+            .get_sequence_with_opts(address, true)
+            .await?
+            .in_range(SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0))
+            .unwrap_or_default();
+        assert_eq!(entries.len(), 5);
+        assert_eq!(entries[4], b"VALUE4".to_vec());
+
+        Ok(())
+    }
+
+    pub async fn sequence_mirror_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let primary = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        let mirror = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        client.register_sequence_mirror(primary, mirror);
+        client
+            .append_to_sequence(primary, b"VALUE1".to_vec())
+            .await?;
+
+        for address in &[primary, mirror] {
+            let (index, entry) = client.get_sequence_last_entry(*address).await?;
+            assert_eq!(index, 0);
+            assert_eq!(entry, b"VALUE1".to_vec());
+        }
+
+        // Once unregistered, appends land in the primary Sequence only
+        assert_eq!(client.unregister_sequence_mirror(primary), Some(mirror));
+        client
+            .append_to_sequence(primary, b"VALUE2".to_vec())
+            .await?;
+        assert_eq!(client.get_sequence_entries_count(primary).await?, 2);
+        assert_eq!(client.get_sequence_entries_count(mirror).await?, 1);
+
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TypedEntry {
+        id: u64,
+        label: String,
+    }
+
+    pub async fn append_typed_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let entry = TypedEntry {
+            id: 1,
+            label: "first".to_string(),
+        };
+        client.append_typed(address, &entry).await?;
+
+        let entries: Vec<TypedEntry> = client
+            .get_sequence_range_typed(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await?;
+        assert_eq!(entries, vec![entry]);
+
+        Ok(())
+    }
+
+    pub async fn sequence_json_entries_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let first = serde_json::json!({ "id": 1, "tags": ["a", "b"] });
+        let last = serde_json::json!("last");
+        client.append_json(address, &first).await?;
+        client
+            .append_to_sequence(address, b"not json".to_vec())
+            .await?;
+        client.append_json(address, &last).await?;
+
+        let entries = client
+            .get_sequence_range_json(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await?;
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].as_ref().ok(), Some(&first));
+        match entries[1] {
+            Err(CoreError::Deserialization(_)) => (),
+            ref res => panic!("Unexpected result: {:?}", res),
+        }
+        assert_eq!(entries[2].as_ref().ok(), Some(&last));
+
+        Ok(())
+    }
+
+    pub async fn get_sequence_range_typed_mismatch_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        // Too short to hold a `TypedEntry`
+        client.append_to_sequence(address, b"x".to_vec()).await?;
+
+        let res = client
+            .get_sequence_range_typed::<TypedEntry>(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await;
+        match res {
+            Err(CoreError::Deserialization(_)) => Ok(()),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+
+    pub async fn sequence_address_from_seed_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let seed: [u8; 32] = rand::random();
+        let tag = 15000;
+
+        let derived_address = sequence_address_from_seed(&seed, tag, Scope::Private);
+        assert_eq!(
+            derived_address,
+            sequence_address_from_seed(&seed, tag, Scope::Private)
+        );
+        assert_ne!(
+            derived_address,
+            sequence_address_from_seed(&seed, tag, Scope::Public)
+        );
+
+        // store at the derived name, which gets us the derived address
+        let address = client
+            .store_private_sequence_owner_only(None, *derived_address.name(), tag)
+            .await?;
+        assert_eq!(address, derived_address);
+
+        client.clear_sequence_cache().await;
+        let sequence = client.get_sequence(derived_address).await?;
+        assert_eq!(*sequence.address(), derived_address);
+
+        Ok(())
+    }
+
+    pub async fn sequence_force_refresh_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+
+        // mutate the local replica without sending the op to the network
+        let mut sequence = client.get_sequence(address).await?;
+        let _op = sequence.append(b"VALUE1".to_vec());
+        client.update_cached_sequence(sequence).await;
+
+        let cached = client.get_sequence(address).await?;
+        assert_eq!(cached.entries_index(), 1);
+
+        let refreshed = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(refreshed.entries_index(), 0);
+
+        // and the local replica was overwritten with the network version
+        let cached = client.get_sequence(address).await?;
+        assert_eq!(cached.entries_index(), 0);
+
+        Ok(())
+    }
+
+    pub async fn can_user_append_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let appender = gen_bls_keypair().public_key();
+        let reader = gen_bls_keypair().public_key();
+
+        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        let _ = perms.insert(
+            appender,
+            SequencePrivUserPermissions::new(true, true, false),
+        );
+        let _ = perms.insert(reader, SequencePrivUserPermissions::new(true, false, false));
+        let address = client
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        assert!(client.can_user_append(address, appender).await?);
+        assert!(!client.can_user_append(address, reader).await?);
+        assert!(
+            !client
+                .can_user_append(address, gen_bls_keypair().public_key())
+                .await?
+        );
+
+        Ok(())
+    }
+
+    pub async fn private_sequence_with_no_permissions_is_owner_only_test() -> Result<(), CoreError>
+    {
+        let mut client = Client::new(None).await?;
+        let mut other_client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let other = other_client.public_key().await;
+
+        let perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let address = client
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        assert!(client.can_user_read(address, owner).await?);
+        assert!(client.can_user_append(address, owner).await?);
+        assert!(!client.can_user_read(address, other).await?);
+        assert!(!client.can_user_append(address, other).await?);
+
+        match other_client.get_sequence(address).await {
+            Err(CoreError::SequenceAccessDenied {
+                action: SequenceAction::Read,
+            }) => (),
+            res => panic!(
+                "Unexpected result reading another owner's Sequence: {:?}",
+                res.map(|_| ())
+            ),
+        }
+
+        Ok(())
+    }
+
+    pub async fn sequence_transaction_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let collaborator = gen_bls_keypair().public_key();
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        let _ = perms.insert(
+            collaborator,
+            SequencePrivUserPermissions::new(true, true, false),
+        );
+        let txn = SequenceTransaction::new()
+            .append(b"VALUE1".to_vec())
+            .set_private_permissions(perms);
+        assert_eq!(txn.len(), 2);
+
+        let balance = client.get_local_balance().await;
+        client.commit_sequence_transaction(address, txn).await?;
+        assert_eq!(
+            client.get_local_balance().await,
+            calculate_new_balance(balance, Some(2), None)
+        );
+
+        // Both applied, as seen by the network
+        let sequence = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 1);
+        assert!(client.can_user_append(address, collaborator).await?);
+
+        // Public permissions can't be set on a private Sequence, so nothing is sent
+        let mut pub_perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = pub_perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, false),
+        );
+        let txn = SequenceTransaction::new()
+            .append(b"VALUE2".to_vec())
+            .set_pub_permissions(pub_perms);
+        let balance = client.get_local_balance().await;
+        assert!(client
+            .commit_sequence_transaction(address, txn)
+            .await
+            .is_err());
+        assert_eq!(client.get_local_balance().await, balance);
+        let sequence = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 1);
+
+        Ok(())
+    }
+
+    pub async fn sequence_permission_helpers_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let user = gen_bls_keypair().public_key();
+
+        let mut perms = pub_perms_for(&[owner], SequencePubUserPermissions::new(true, true));
+        perms.extend(anyone_read_only());
+        let address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        let stored = client
+            .get_sequence_pub_permissions(address)
+            .await?
+            .permissions;
+        assert_eq!(stored.len(), 2);
+        let anyone = stored[&SequenceUser::Anyone];
+        assert_eq!(anyone.is_allowed(SequenceAction::Append), Some(false));
+        assert_eq!(
+            anyone.is_allowed(SequenceAction::ManagePermissions),
+            Some(false)
+        );
+        assert_eq!(
+            stored[&SequenceUser::Key(owner)].is_allowed(SequenceAction::Append),
+            Some(true)
+        );
+
+        let mut perms =
+            private_perms_for(&[user], SequencePrivUserPermissions::new(true, true, false));
+        perms.extend(private_perms_for(
+            &[owner],
+            SequencePrivUserPermissions::new(true, true, true),
+        ));
+        let address = client
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        let stored = client
+            .get_sequence_private_permissions(address)
+            .await?
+            .permissions;
+        assert_eq!(stored.len(), 2);
+        assert!(stored[&user].is_allowed(SequenceAction::Append));
+        assert!(!stored[&user].is_allowed(SequenceAction::ManagePermissions));
+        assert!(stored[&owner].is_allowed(SequenceAction::ManagePermissions));
+
+        Ok(())
+    }
+
+    pub async fn sequence_private_permissions_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+        let owner = client.public_key().await;
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+
+        let data = client.get_sequence(address).await?;
+        assert_eq!(data.entries_index(), 0);
+        assert_eq!(data.owners_index(), 1);
+        assert_eq!(data.permissions_index(), 1);
+
+        let private_permissions = client.get_sequence_private_permissions(address).await?;
+        let user_perms = private_permissions
+            .permissions
+            .get(&owner)
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert!(user_perms.is_allowed(SequenceAction::Read));
+        assert!(user_perms.is_allowed(SequenceAction::Append));
+        assert!(user_perms.is_allowed(SequenceAction::ManagePermissions));
+
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Key(owner))
+            .await?
+        {
+            SequenceUserPermissions::Priv(user_perms) => {
+                assert!(user_perms.is_allowed(SequenceAction::Read));
+                assert!(user_perms.is_allowed(SequenceAction::Append));
+                assert!(user_perms.is_allowed(SequenceAction::ManagePermissions));
+            }
+            SequenceUserPermissions::Public(_) => {
+                return Err(CoreError::from(
+                    "Unexpectedly obtained incorrect user permissions",
+                ))
+            }
+        }
+
+        let sim_client = gen_bls_keypair().public_key();
+        let mut perms2 = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms2.insert(
+            sim_client,
+            SequencePrivUserPermissions::new(false, true, false),
+        );
+        client
+            .sequence_set_private_permissions(address, perms2)
+            .await?;
+
+        let private_permissions = client.get_sequence_private_permissions(address).await?;
+        let user_perms = private_permissions
+            .permissions
+            .get(&sim_client)
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert!(!user_perms.is_allowed(SequenceAction::Read));
+        assert!(user_perms.is_allowed(SequenceAction::Append));
+        assert!(!user_perms.is_allowed(SequenceAction::ManagePermissions));
+
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Key(sim_client))
+            .await?
+        {
+            SequenceUserPermissions::Priv(user_perms) => {
+                assert!(!user_perms.is_allowed(SequenceAction::Read));
+                assert!(user_perms.is_allowed(SequenceAction::Append));
+                assert!(!user_perms.is_allowed(SequenceAction::ManagePermissions));
+                Ok(())
+            }
+            SequenceUserPermissions::Public(_) => Err(CoreError::from(
+                "Unexpectedly obtained incorrect user permissions",
+            )),
+        }
+    }
+
+    pub async fn frozen_sequence_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let user = gen_bls_keypair().public_key();
+
+        let mut perms = pub_perms_for(&[user], SequencePubUserPermissions::new(true, false));
+        perms.extend(anyone_read_only());
+        let address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        // Revoke the append permission of the only user granted it
+        let mut perms = pub_perms_for(&[user], SequencePubUserPermissions::new(false, false));
+        perms.extend(anyone_read_only());
+        client.sequence_set_pub_permissions(address, perms).await?;
+
+        assert!(client.is_sequence_frozen(address).await?);
+
+        Ok(())
+    }
+
+    pub async fn sequence_open_for_appends_is_not_frozen_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, false),
+        );
+        let address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        assert!(!client.is_sequence_frozen(address).await?);
+
+        Ok(())
+    }
+
+    pub async fn sequence_revoke_user_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+        let owner = client.public_key().await;
+        let user1 = gen_bls_keypair().public_key();
+        let user2 = gen_bls_keypair().public_key();
+
+        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        let _ = perms.insert(user1, SequencePrivUserPermissions::new(true, true, false));
+        let _ = perms.insert(user2, SequencePrivUserPermissions::new(true, false, false));
+        let address = client
+            .store_private_sequence(None, name, tag, owner, perms)
+            .await?;
+
+        client.sequence_revoke_user(address, user1).await?;
+
+        let private_permissions = client.get_sequence_private_permissions(address).await?;
+        assert!(private_permissions.permissions.get(&user1).is_none());
+        let user_perms = private_permissions
+            .permissions
+            .get(&user2)
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert!(user_perms.is_allowed(SequenceAction::Read));
+        assert!(!user_perms.is_allowed(SequenceAction::Append));
+        assert!(private_permissions.permissions.get(&owner).is_some());
+
+        // revoking a user without permissions fails
+        match client.sequence_revoke_user(address, user1).await {
+            Err(CoreError::NoSuchUserPermissions {
+                user: SequenceUser::Key(user),
+            }) if user == user1 => Ok(()),
+            res => Err(CoreError::from(format!(
+                "Unexpected result when revoking a user without permissions: {:?}",
+                res
+            ))),
+        }
+    }
+
+    pub async fn grant_sequence_users_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let first_batch: Vec<_> = (0..2).map(|_| gen_bls_keypair().public_key()).collect();
+        let second_batch: Vec<_> = (0..2).map(|_| gen_bls_keypair().public_key()).collect();
+        client
+            .grant_sequence_users(
+                address,
+                private_perms_for(
+                    &first_batch,
+                    SequencePrivUserPermissions::new(true, true, false),
+                ),
+            )
+            .await?;
+        client
+            .grant_sequence_users(
+                address,
+                private_perms_for(
+                    &second_batch,
+                    SequencePrivUserPermissions::new(true, false, false),
+                ),
+            )
+            .await?;
+
+        let permissions = client
+            .get_sequence_private_permissions(address)
+            .await?
+            .permissions;
+        assert_eq!(permissions.len(), 5);
+        assert!(permissions
+            .get(&owner)
+            .ok_or_else(|| CoreError::from("Owner's permissions were not preserved"))?
+            .is_allowed(SequenceAction::ManagePermissions));
+        for user in &first_batch {
+            assert!(client.can_user_append(address, *user).await?);
+        }
+        for user in &second_batch {
+            assert!(client.can_user_read(address, *user).await?);
+            assert!(!client.can_user_append(address, *user).await?);
+        }
+
+        Ok(())
+    }
+
+    pub async fn sequence_pub_permissions_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+        let owner = client.public_key().await;
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Key(owner),
+            SequencePubUserPermissions::new(None, true),
+        );
+        let address = client
+            .store_public_sequence(None, name, tag, owner, perms)
+            .await?;
+
+        let data = client.get_sequence(address).await?;
+        assert_eq!(data.entries_index(), 0);
+        assert_eq!(data.owners_index(), 1);
+        assert_eq!(data.permissions_index(), 1);
+
+        let pub_permissions = client.get_sequence_pub_permissions(address).await?;
+        let user_perms = pub_permissions
+            .permissions
+            .get(&SequenceUser::Key(owner))
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
+        assert_eq!(None, user_perms.is_allowed(SequenceAction::Append));
+        assert_eq!(
+            Some(true),
+            user_perms.is_allowed(SequenceAction::ManagePermissions)
+        );
+
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Key(owner))
+            .await?
+        {
+            SequenceUserPermissions::Public(user_perms) => {
+                assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
+                assert_eq!(None, user_perms.is_allowed(SequenceAction::Append));
+                assert_eq!(
+                    Some(true),
+                    user_perms.is_allowed(SequenceAction::ManagePermissions)
+                );
+            }
+            SequenceUserPermissions::Priv(_) => {
+                return Err(CoreError::from(
+                    "Unexpectedly obtained incorrect user permissions",
+                ))
+            }
+        }
+
+        let sim_client = gen_bls_keypair().public_key();
+        let mut perms2 = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms2.insert(
+            SequenceUser::Key(sim_client),
+            SequencePubUserPermissions::new(false, false),
+        );
+        client.sequence_set_pub_permissions(address, perms2).await?;
+
+        let pub_permissions = client.get_sequence_pub_permissions(address).await?;
+        let user_perms = pub_permissions
+            .permissions
+            .get(&SequenceUser::Key(sim_client))
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
+        assert_eq!(Some(false), user_perms.is_allowed(SequenceAction::Append));
+        assert_eq!(
+            Some(false),
+            user_perms.is_allowed(SequenceAction::ManagePermissions)
+        );
+
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Key(sim_client))
+            .await?
+        {
+            SequenceUserPermissions::Public(user_perms) => {
+                assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
+                assert_eq!(Some(false), user_perms.is_allowed(SequenceAction::Append));
+                assert_eq!(
+                    Some(false),
+                    user_perms.is_allowed(SequenceAction::ManagePermissions)
+                );
+                Ok(())
+            }
+            SequenceUserPermissions::Priv(_) => Err(CoreError::from(
+                "Unexpectedly obtained incorrect user permissions",
+            )),
+        }
+    }
+
+    pub async fn sequence_permissions_history_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+        let owner = client.public_key().await;
+        let user = gen_bls_keypair().public_key();
+
+        let mut perms = owner_only_perms(owner);
+        let address = client
+            .store_private_sequence(None, name, tag, owner, perms.clone())
+            .await?;
+
+        // change the permissions twice
+        let _ = perms.insert(user, SequencePrivUserPermissions::new(true, false, false));
+        client
+            .sequence_set_private_permissions(address, perms.clone())
+            .await?;
+        let _ = perms.insert(user, SequencePrivUserPermissions::new(true, true, false));
+        client
+            .sequence_set_private_permissions(address, perms)
+            .await?;
+
+        let first = client
+            .get_sequence_private_permissions_at(address, 0)
+            .await?;
+        assert!(first.permissions.get(&user).is_none());
+
+        let second = client
+            .get_sequence_private_permissions_at(address, 1)
+            .await?;
+        let user_perms = second
+            .permissions
+            .get(&user)
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert!(!user_perms.is_allowed(SequenceAction::Append));
+
+        let third = client
+            .get_sequence_private_permissions_at(address, 2)
+            .await?;
+        let user_perms = third
+            .permissions
+            .get(&user)
+            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
+        assert!(user_perms.is_allowed(SequenceAction::Append));
+
+        let history = client.get_sequence_permissions_history(address).await?;
+        assert_eq!(history.len(), 3);
+
+        match client.get_sequence_private_permissions_at(address, 3).await {
+            Err(CoreError::DataError(_)) => Ok(()),
+            res => Err(CoreError::from(format!(
+                "Unexpected result when reading out of range permissions: {:?}",
+                res
+            ))),
+        }
+    }
+
+    pub async fn append_to_sequence_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let owner = client.public_key().await;
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Key(owner),
+            SequencePubUserPermissions::new(true, true),
+        );
+        let address = client
+            .store_public_sequence(None, name, tag, owner, perms)
+            .await?;
+
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        let (index, data) = client.get_sequence_last_entry(address).await?;
+        assert_eq!(0, index);
+        assert_eq!(unwrap!(std::str::from_utf8(&data)), "VALUE1");
+
+        client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+
+        let (index, data) = client.get_sequence_last_entry(address).await?;
+        assert_eq!(1, index);
+        assert_eq!(unwrap!(std::str::from_utf8(&data)), "VALUE2");
+
+        let data = client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await?;
+        assert_eq!(unwrap!(std::str::from_utf8(&data[0])), "VALUE1");
+        assert_eq!(unwrap!(std::str::from_utf8(&data[1])), "VALUE2");
+
+        Ok(())
+    }
+
+    pub async fn append_to_sequence_with_stale_permissions_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+        let mut other_client = Client::new(None).await?;
+
+        let owner = client.public_key().await;
+        let other = other_client.public_key().await;
+
+        // the other client isn't allowed to append yet
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Key(owner),
+            SequencePubUserPermissions::new(true, true),
+        );
+        let _ = perms.insert(
+            SequenceUser::Key(other),
+            SequencePubUserPermissions::new(false, false),
+        );
+        let address = client
+            .store_public_sequence(None, name, tag, owner, perms.clone())
+            .await?;
+
+        // populate the other client's local replica
+        let _ = other_client.get_sequence(address).await?;
+
+        // the owner now grants append permission, leaving the other client's replica stale
+        let _ = perms.insert(
+            SequenceUser::Key(other),
+            SequencePubUserPermissions::new(true, false),
+        );
+        client.sequence_set_pub_permissions(address, perms).await?;
+
+        other_client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        let sequence = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 1);
+        assert_eq!(
+            unwrap!(std::str::from_utf8(&unwrap!(sequence.last_entry()))),
+            "VALUE1"
+        );
+
+        Ok(())
+    }
+
+    pub async fn failed_append_is_not_kept_in_local_replica_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 10)
+            .await?;
+
+        let transport = Arc::new(FlakyTransport::new(
+            client.connection_manager.clone(),
+            1,
+            |msg| match msg {
+                Message::Cmd {
+                    cmd:
+                        Cmd::Data {
+                            cmd: DataCmd::Sequence(SequenceWrite::Edit(_)),
+                            ..
+                        },
+                    ..
+                } => true,
+                _ => false,
+            },
+        ));
+        client.connection_manager = ConnectionManager::with_transport(
+            Config::new().quic_p2p,
+            client.full_id.clone(),
+            transport,
+        )?;
+
+        match client.append_to_sequence(address, b"VALUE1".to_vec()).await {
+            Err(CoreError::ConnectionLost) => (),
+            res => panic!("Unexpected result of a failed append: {:?}", res),
+        }
+
+        // the append isn't served from the local replica, nor merged into the network's one
+        assert_eq!(client.get_sequence(address).await?.entries_index(), 0);
+        assert_eq!(
+            client
+                .append_to_sequence_indexed(address, b"VALUE2".to_vec())
+                .await?,
+            0
+        );
+
+        Ok(())
+    }
+
+    pub async fn append_rejected_by_the_network_is_forgotten_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 10)
+            .await?;
+
+        // as if sent, but rejected by the network's replicas
+        let (_, rejected) = client
+            .append_locally(address, b"VALUE1".to_vec(), false, false)
+            .await?;
+        client
+            .pending_sequence_ops
+            .lock()
+            .await
+            .entry(address)
+            .or_default()
+            .push(rejected);
+
+        // the network's replicas apply the next append, moving past the rejected one
+        client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+
+        let sequence = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 1);
+        assert_eq!(
+            unwrap!(std::str::from_utf8(&unwrap!(sequence.last_entry()))),
+            "VALUE2"
+        );
+        assert!(client
+            .pending_sequence_ops
+            .lock()
+            .await
+            .get(&address)
+            .is_none());
+
+        Ok(())
+    }
+
+    pub async fn append_to_sequence_without_permission_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+        let mut other_client = Client::new(None).await?;
+
+        let owner = client.public_key().await;
+        let other = other_client.public_key().await;
+
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Key(owner),
+            SequencePubUserPermissions::new(true, true),
+        );
+        let _ = perms.insert(
+            SequenceUser::Key(other),
+            SequencePubUserPermissions::new(false, false),
+        );
+        let address = client
+            .store_public_sequence(None, name, tag, owner, perms)
+            .await?;
+
+        match other_client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await
+        {
+            Err(CoreError::SequenceAccessDenied {
+                action: SequenceAction::Append,
+            }) => Ok(()),
+            res => Err(CoreError::from(format!(
+                "Unexpected result when appending without permission: {:?}",
+                res
+            ))),
+        }
+    }
+
+    pub async fn copy_sequence_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let tag = 15000;
+
+        let perms = owner_only_perms(owner);
+        let entries = vec![b"VALUE1".to_vec(), b"VALUE2".to_vec(), b"VALUE3".to_vec()];
+        let (src, _) = client
+            .store_private_sequence_with_entries(entries, XorName::random(), tag, owner, perms)
+            .await?;
+
+        let dst_name = XorName::random();
+        let dst = client.copy_sequence(src, dst_name, tag).await?;
+        assert!(dst.is_private());
+        assert_eq!(*dst.name(), dst_name);
+
+        client.clear_sequence_cache().await;
+        let full_range = (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0));
+        let src_entries = client.get_sequence_range(src, full_range).await?;
+        let dst_entries = client.get_sequence_range(dst, full_range).await?;
+        assert_eq!(src_entries.len(), 3);
+        assert_eq!(src_entries, dst_entries);
+
+        Ok(())
+    }
+
+    pub async fn store_sequence_if_absent_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let name = XorName::random();
+
+        let perms = owner_only_perms(owner);
+        let address = client
+            .store_sequence_if_absent(None, name, 15000, owner, perms.clone())
+            .await?;
+        assert_eq!(address, SequenceAddress::Private { name, tag: 15000 });
+        let balance_after_store = client.get_balance().await?;
+
+        match client
+            .store_sequence_if_absent(None, name, 15000, owner, perms)
+            .await
+        {
+            Err(CoreError::DataError(SndError::DataExists)) => (),
+            res => panic!("Unexpected result for an existing Sequence: {:?}", res),
+        }
+
+        // The second attempt wasn't paid for
+        assert_eq!(client.get_local_balance().await, balance_after_store);
+        assert_eq!(client.get_balance().await?, balance_after_store);
+
+        Ok(())
+    }
+
+    pub async fn store_private_sequence_owner_only_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        let sequence = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 1);
+        assert_eq!(unwrap!(sequence.owner(0)).public_key, owner);
+
+        Ok(())
+    }
+
+    pub async fn sequence_builder_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let other = gen_bls_keypair().public_key();
+
+        let name = XorName::random();
+        let address = SequenceBuilder::new()
+            .private()
+            .name(name)
+            .tag(15000)
+            .grant(
+                SequenceUser::Key(owner),
+                SequencePrivUserPermissions::new(true, true, true),
+            )
+            .grant(
+                SequenceUser::Key(other),
+                SequencePrivUserPermissions::new(true, false, false),
+            )
+            .store(&mut client)
+            .await?;
+        assert!(address.is_private());
+        assert_eq!(*address.name(), name);
+        assert_eq!(address.tag(), 15000);
+        assert_eq!(client.get_sequence_owner(address).await?.public_key, owner);
+        let perms = client.get_sequence_private_permissions(address).await?;
+        assert!(perms.permissions.contains_key(&owner));
+        assert!(perms.permissions.contains_key(&other));
+
+        let address = SequenceBuilder::new()
+            .public()
+            .name(XorName::random())
+            .tag(15001)
+            .owner(other)
+            .grant(
+                SequenceUser::Anyone,
+                SequencePubUserPermissions::new(true, false),
+            )
+            .store(&mut client)
+            .await?;
+        assert!(address.is_pub());
+        assert_eq!(client.get_sequence_owner(address).await?.public_key, other);
+        let perms = client.get_sequence_pub_permissions(address).await?;
+        assert!(perms.permissions.contains_key(&SequenceUser::Anyone));
+
+        Ok(())
+    }
+
+    pub async fn sequence_builder_validation_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let balance = client.get_local_balance().await;
+
+        let mismatched = SequenceBuilder::new()
+            .public()
+            .name(XorName::random())
+            .tag(15000)
+            .grant(
+                SequenceUser::Key(owner),
+                SequencePrivUserPermissions::new(true, true, true),
+            )
+            .store(&mut client)
+            .await;
+        assert!(mismatched.is_err());
+
+        let anyone_on_private = SequenceBuilder::new()
+            .name(XorName::random())
+            .tag(15000)
+            .grant(
+                SequenceUser::Anyone,
+                SequencePrivUserPermissions::new(true, false, false),
+            )
+            .store(&mut client)
+            .await;
+        assert!(anyone_on_private.is_err());
+
+        let nameless = SequenceBuilder::new().tag(15000).store(&mut client).await;
+        assert!(nameless.is_err());
+
+        // Nothing was paid for
+        assert_eq!(client.get_local_balance().await, balance);
+
+        Ok(())
+    }
+
+    pub async fn sequence_user_permissions_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        let collaborator = gen_bls_keypair().public_key();
+        let stranger = gen_bls_keypair().public_key();
+
+        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        let _ = perms.insert(
+            collaborator,
+            SequencePrivUserPermissions::new(true, true, false),
+        );
+        let address = client
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Key(collaborator))
+            .await?
+        {
+            SequenceUserPermissions::Priv(perms) => {
+                assert!(perms.is_allowed(SequenceAction::Read));
+                assert!(perms.is_allowed(SequenceAction::Append));
+                assert!(!perms.is_allowed(SequenceAction::ManagePermissions));
+            }
+            perms => panic!("Unexpected permissions: {:?}", perms),
+        }
+
+        for user in vec![SequenceUser::Key(stranger), SequenceUser::Anyone] {
+            match client.get_sequence_user_permissions(address, user).await {
+                Err(CoreError::NoSuchUserPermissions { user: missing }) => {
+                    assert_eq!(missing, user)
+                }
+                res => panic!("Unexpected result: {:?}", res),
+            }
+        }
+
+        // Anyone can be granted permissions on a Public Sequence
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, false),
+        );
+        let address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Anyone)
+            .await?
+        {
+            SequenceUserPermissions::Public(perms) => {
+                assert_eq!(Some(true), perms.is_allowed(SequenceAction::Append));
+            }
+            perms => panic!("Unexpected permissions: {:?}", perms),
+        }
+        match client
+            .get_sequence_user_permissions(address, SequenceUser::Key(stranger))
+            .await
+        {
+            Err(CoreError::NoSuchUserPermissions { .. }) => Ok(()),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+
+    pub async fn sequence_owner_and_permissions_getters_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+
+        let private_address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, false),
+        );
+        let public_address = client
+            .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+            .await?;
+
+        for address in &[private_address, public_address] {
+            let sequence_owner = client.get_sequence_owner(*address).await?;
+            assert_eq!(sequence_owner.public_key, owner);
+        }
+
+        let private_perms = client
+            .get_sequence_private_permissions(private_address)
+            .await?;
+        assert!(private_perms.permissions.contains_key(&owner));
+        let pub_perms = client.get_sequence_pub_permissions(public_address).await?;
+        assert!(pub_perms.permissions.contains_key(&SequenceUser::Anyone));
+
+        // Asking for the permissions of the other scope
+        match client.get_sequence_pub_permissions(private_address).await {
+            Err(CoreError::WrongSequenceScope {
+                expected: Scope::Public,
+            }) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+        match client
+            .get_sequence_private_permissions(public_address)
+            .await
+        {
+            Err(CoreError::WrongSequenceScope {
+                expected: Scope::Private,
+            }) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        // Which isn't the same as asking for a Sequence which doesn't exist
+        let missing_address = SequenceAddress::Private {
+            name: XorName::random(),
+            tag: 15000,
+        };
+        match client
+            .get_sequence_private_permissions(missing_address)
+            .await
+        {
+            Err(CoreError::DataError(SndError::NoSuchData)) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+        match client.get_sequence_owner(missing_address).await {
+            Err(CoreError::DataError(SndError::NoSuchData)) => Ok(()),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+
+    pub async fn my_sequences_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let owner = client.public_key().await;
+        assert!(client.my_sequences().is_empty());
+
+        let mut addresses = Vec::new();
+        for _ in 0..2 {
+            addresses.push(
+                client
+                    .store_private_sequence_owner_only(None, XorName::random(), 15000)
+                    .await?,
+            );
+        }
+        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
+        let _ = perms.insert(
+            SequenceUser::Anyone,
+            SequencePubUserPermissions::new(true, false),
+        );
+        addresses.push(
+            client
+                .store_public_sequence(None, XorName::random(), 15000, owner, perms)
+                .await?,
+        );
+
+        // Known to the clones too, in the order they were stored
+        assert_eq!(client.clone().my_sequences(), addresses);
+
+        client.delete_sequence(addresses[0]).await?;
+        assert_eq!(client.my_sequences(), addresses[1..].to_vec());
+
+        Ok(())
+    }
+
+    pub async fn broadcast_sequence_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let mut other_client = Client::new(None).await?;
+
+        let address = client
+            .store_broadcast_sequence(XorName::random(), 15000)
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        // Anyone can read it
+        let (_index, last_entry) = other_client.get_sequence_last_entry(address).await?;
+        assert_eq!(last_entry, b"VALUE1".to_vec());
+
+        // But only the owner can append to it
+        match other_client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await
+        {
+            Err(CoreError::SequenceAccessDenied {
+                action: SequenceAction::Append,
+            }) => Ok(()),
+            res => Err(CoreError::from(format!(
+                "Unexpected result when appending to a broadcast Sequence: {:?}",
+                res
+            ))),
+        }
+    }
+
+    pub async fn append_to_sequence_in_dry_run_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+
+        client.set_dry_run(true);
+        let balance = client.get_balance().await?;
+
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+
+        // the local replica was updated...
+        let (index, data) = client.get_sequence_last_entry(address).await?;
+        assert_eq!(0, index);
+        assert_eq!(unwrap!(std::str::from_utf8(&data)), "VALUE1");
+
+        // ...but nothing was paid nor sent to the network
+        assert_eq!(client.get_local_balance().await, balance);
+        assert_eq!(client.get_balance().await?, balance);
+        let sequence = client.get_sequence_with_opts(address, true).await?;
+        assert_eq!(sequence.entries_index(), 0);
+
+        Ok(())
+    }
+
+    pub async fn sequence_entries_count_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+        assert_eq!(client.get_sequence_entries_count(address).await?, 0);
+
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+
+        assert_eq!(client.get_sequence_entries_count(address).await?, 2);
+
+        Ok(())
+    }
+
+    pub async fn sequence_entry_stream_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let owner = client.public_key().await;
+        let perms = owner_only_perms(owner);
+        let entries = vec![
+            b"VALUE1".to_vec(),
+            b"VALUE2".to_vec(),
+            b"VALUE3".to_vec(),
+            b"VALUE4".to_vec(),
+            b"VALUE5".to_vec(),
+        ];
+        let address = client
+            .store_private_sequence(Some(entries), name, tag, owner, perms)
+            .await?;
+
+        let expected = client
+            .get_sequence_range(
+                address,
+                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
+            )
+            .await?;
+
+        // use a page size which doesn't evenly divide the number of entries
+        let stream = client
+            .sequence_entry_stream_with_page_size(address, 2)
+            .await?;
+        let streamed = stream
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, CoreError>>()?;
+
+        assert_eq!(streamed.len(), expected.len());
+        for (position, (index, entry)) in streamed.into_iter().enumerate() {
+            assert_eq!(index, position as u64);
+            assert_eq!(entry, expected[position]);
+        }
+
+        Ok(())
+    }
+
+    pub async fn sequence_first_entry_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+
+        match client.get_sequence_first_entry(address).await {
+            Err(CoreError::DataError(SndError::NoSuchEntry)) => (),
+            res => panic!("Unexpected result from an empty Sequence: {:?}", res),
+        }
+
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE3".to_vec())
+            .await?;
+
+        let (index, data) = client.get_sequence_first_entry(address).await?;
+        assert_eq!(0, index);
+        assert_eq!(unwrap!(std::str::from_utf8(&data)), "VALUE1");
+
+        Ok(())
+    }
+
+    pub async fn sequence_owner_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let owner = client.public_key().await;
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+
+        client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+        client
+            .append_to_sequence(address, b"VALUE2".to_vec())
+            .await?;
+
+        let data = client.get_sequence(address).await?;
+        assert_eq!(data.entries_index(), 2);
+        assert_eq!(data.owners_index(), 1);
+        assert_eq!(data.permissions_index(), 1);
+
+        let current_owner = client.get_sequence_owner(address).await?;
+        assert_eq!(owner, current_owner.public_key);
+
+        let sim_client = gen_bls_keypair().public_key();
+        client.sequence_set_owner(address, sim_client).await?;
+
+        let current_owner = client.get_sequence_owner(address).await?;
+        assert_eq!(sim_client, current_owner.public_key);
+
+        Ok(())
+    }
+
+    pub async fn sequence_owner_history_test() -> Result<(), CoreError> {
+        let name = XorName(rand::random());
+        let tag = 10;
+        let mut client = Client::new(None).await?;
+
+        let owner = client.public_key().await;
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+
+        let second_owner = gen_bls_keypair().public_key();
+        let third_owner = gen_bls_keypair().public_key();
+        client.sequence_set_owner(address, second_owner).await?;
+        client.sequence_set_owner(address, third_owner).await?;
+
+        let history = client.get_sequence_owner_history(address).await?;
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].public_key, owner);
+        assert_eq!(history[1].public_key, second_owner);
+        assert_eq!(history[2].public_key, third_owner);
+
+        Ok(())
+    }
+
+    pub async fn sequence_can_delete_private_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let name = XorName(rand::random());
+        let tag = 15000;
+
+        // store a Private Sequence
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+        let sequence = client.get_sequence(address).await?;
+        assert!(sequence.is_private());
+
+        client.delete_sequence(address).await?;
+
+        match client.get_sequence(address).await {
+            Err(CoreError::DataError(SndError::NoSuchData)) => Ok(()),
+            Err(err) => Err(CoreError::from(format!(
+                "Unexpected error returned when deleting a nonexisting Private Sequence: {}",
+                err
+            ))),
+            Ok(_res) => Err(CoreError::from(
+                "Unexpectedly retrieved a deleted Private Sequence!",
+            )),
+        }
     }
-}
 
-#[allow(missing_docs)]
-#[cfg(any(test, feature = "simulated-payouts", feature = "testing"))]
-pub mod exported_tests {
-    use super::*;
-    use crate::utils::test_utils::gen_bls_keypair;
-    use safe_nd::{Error as SndError, Money, SequencePrivUserPermissions};
-    use std::str::FromStr;
-    use unwrap::unwrap;
-    use xor_name::XorName;
+    pub async fn sequence_deletion_invalidates_cache_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
 
-    pub async fn sequence_deletions_should_cost_put_price() -> Result<(), CoreError> {
         let name = XorName(rand::random());
-        let tag = 10;
-        let mut client = Client::new(None).await?;
-        let owner = client.public_key().await;
-        let perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
-        let sequence_address = client
-            .store_private_sequence(None, name, tag, owner, perms)
-            .await?;
+        let tag = 15000;
 
-        let balance_before_delete = client.get_balance().await?;
-        client.delete_sequence(sequence_address).await?;
-        let new_balance = client.get_balance().await?;
+        // store a Private Sequence
+        let address = client
+            .store_private_sequence_owner_only(None, name, tag)
+            .await?;
+        assert!(client.sequence_cache.lock().await.contains(&address));
 
-        // make sure we have _some_ balance
-        assert_ne!(balance_before_delete, Money::from_str("0")?);
-        assert_ne!(balance_before_delete, new_balance);
+        client.delete_sequence(address).await?;
+        assert!(!client.sequence_cache.lock().await.contains(&address));
 
         Ok(())
     }
 
-    /// Sequence data tests ///
-
-    pub async fn sequence_basics_test() -> Result<(), CoreError> {
+    pub async fn sequence_cannot_delete_public_test() -> Result<(), CoreError> {
         let mut client = Client::new(None).await?;
 
         let name = XorName(rand::random());
         let tag = 15000;
         let owner = client.public_key().await;
 
-        // store a Private Sequence
-        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
-        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
-        let address = client
-            .store_private_sequence(None, name, tag, owner, perms)
-            .await?;
-        let sequence = client.get_sequence(address).await?;
-        assert!(sequence.is_private());
-        assert_eq!(*sequence.name(), name);
-        assert_eq!(sequence.tag(), tag);
-        assert_eq!(sequence.permissions_index(), 1);
-        assert_eq!(sequence.owners_index(), 1);
-        assert_eq!(sequence.entries_index(), 0);
-
         // store a Public Sequence
         let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
         let _ = perms.insert(
@@ -844,340 +6246,405 @@ pub mod exported_tests {
             .await?;
         let sequence = client.get_sequence(address).await?;
         assert!(sequence.is_pub());
-        assert_eq!(*sequence.name(), name);
-        assert_eq!(sequence.tag(), tag);
-        assert_eq!(sequence.permissions_index(), 1);
-        assert_eq!(sequence.owners_index(), 1);
-        assert_eq!(sequence.entries_index(), 0);
+
+        // Rejected before paying for the deletion
+        let balance = client.get_local_balance().await;
+        match client.delete_sequence(address).await {
+            Err(CoreError::OperationForbidden) => (),
+            res => panic!(
+                "Unexpected result when deleting a Public Sequence: {:?}",
+                res
+            ),
+        }
+        assert_eq!(client.get_local_balance().await, balance);
+
+        // Check that our data still exists.
+        let _ = client.get_sequence_with_opts(address, true).await?;
 
         Ok(())
     }
 
-    pub async fn sequence_private_permissions_test() -> Result<(), CoreError> {
+    pub async fn sequence_cannot_delete_private_of_another_owner_test() -> Result<(), CoreError> {
         let mut client = Client::new(None).await?;
-
-        let name = XorName(rand::random());
-        let tag = 15000;
         let owner = client.public_key().await;
-        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
-        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        let other = gen_bls_keypair().public_key();
+
+        let perms = owner_only_perms(owner);
         let address = client
-            .store_private_sequence(None, name, tag, owner, perms)
+            .store_private_sequence(None, XorName::random(), 15000, other, perms)
             .await?;
 
-        let data = client.get_sequence(address).await?;
-        assert_eq!(data.entries_index(), 0);
-        assert_eq!(data.owners_index(), 1);
-        assert_eq!(data.permissions_index(), 1);
+        let balance = client.get_local_balance().await;
+        match client.delete_sequence(address).await {
+            Err(CoreError::OperationForbidden) => (),
+            res => panic!(
+                "Unexpected result when deleting a Sequence of another owner: {:?}",
+                res
+            ),
+        }
+        assert_eq!(client.get_local_balance().await, balance);
 
-        let private_permissions = client.get_sequence_private_permissions(address).await?;
-        let user_perms = private_permissions
-            .permissions
-            .get(&owner)
-            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
-        assert!(user_perms.is_allowed(SequenceAction::Read));
-        assert!(user_perms.is_allowed(SequenceAction::Append));
-        assert!(user_perms.is_allowed(SequenceAction::ManagePermissions));
+        Ok(())
+    }
 
-        match client
-            .get_sequence_user_permissions(address, SequenceUser::Key(owner))
-            .await?
-        {
-            SequenceUserPermissions::Priv(user_perms) => {
-                assert!(user_perms.is_allowed(SequenceAction::Read));
-                assert!(user_perms.is_allowed(SequenceAction::Append));
-                assert!(user_perms.is_allowed(SequenceAction::ManagePermissions));
-            }
-            SequenceUserPermissions::Public(_) => {
-                return Err(CoreError::from(
-                    "Unexpectedly obtained incorrect user permissions",
-                ))
-            }
-        }
+    pub async fn append_to_deleted_sequence_test() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let clock = Arc::new(MockClock::new());
+        client.clock = clock.clone();
+        let mut owner_client = Client::new(None).await?;
+        let user = client.public_key().await;
+        let owner = owner_client.public_key().await;
 
-        let sim_client = gen_bls_keypair().public_key();
-        let mut perms2 = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
-        let _ = perms2.insert(
-            sim_client,
-            SequencePrivUserPermissions::new(false, true, false),
-        );
-        client
-            .sequence_set_private_permissions(address, perms2)
+        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms.insert(user, SequencePrivUserPermissions::new(true, true, false));
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
+        let address = client
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms)
             .await?;
 
-        let private_permissions = client.get_sequence_private_permissions(address).await?;
-        let user_perms = private_permissions
-            .permissions
-            .get(&sim_client)
-            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
-        assert!(!user_perms.is_allowed(SequenceAction::Read));
-        assert!(user_perms.is_allowed(SequenceAction::Append));
-        assert!(!user_perms.is_allowed(SequenceAction::ManagePermissions));
-
-        match client
-            .get_sequence_user_permissions(address, SequenceUser::Key(sim_client))
-            .await?
-        {
-            SequenceUserPermissions::Priv(user_perms) => {
-                assert!(!user_perms.is_allowed(SequenceAction::Read));
-                assert!(user_perms.is_allowed(SequenceAction::Append));
-                assert!(!user_perms.is_allowed(SequenceAction::ManagePermissions));
-                Ok(())
-            }
-            SequenceUserPermissions::Public(_) => Err(CoreError::from(
-                "Unexpectedly obtained incorrect user permissions",
-            )),
+        // Deleted by its owner, while still in our local replica
+        owner_client.delete_sequence(address).await?;
+        assert!(client.sequence_cache.lock().await.contains(&address));
+        // Checked for being deleted once fetched long enough ago
+        clock.advance(SEQUENCE_DELETED_CHECK_INTERVAL);
+
+        let balance = client.get_local_balance().await;
+        match client.append_to_sequence(address, b"VALUE1".to_vec()).await {
+            Err(CoreError::SequenceDeleted) => (),
+            res => panic!(
+                "Unexpected result appending to a deleted Sequence: {:?}",
+                res
+            ),
         }
+        assert!(!client.sequence_cache.lock().await.contains(&address));
+        // Nothing was paid for the append
+        assert_eq!(client.get_local_balance().await, balance);
+
+        Ok(())
     }
 
-    pub async fn sequence_pub_permissions_test() -> Result<(), CoreError> {
+    pub async fn append_to_cached_private_sequence_refreshes_it_test() -> Result<(), CoreError> {
         let mut client = Client::new(None).await?;
+        let clock = Arc::new(MockClock::new());
+        client.clock = clock.clone();
+        let mut owner_client = Client::new(None).await?;
+        let user = client.public_key().await;
+        let owner = owner_client.public_key().await;
 
-        let name = XorName(rand::random());
-        let tag = 15000;
-        let owner = client.public_key().await;
-        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
-        let _ = perms.insert(
-            SequenceUser::Key(owner),
-            SequencePubUserPermissions::new(None, true),
-        );
+        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
+        let _ = perms.insert(user, SequencePrivUserPermissions::new(true, true, false));
+        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
         let address = client
-            .store_public_sequence(None, name, tag, owner, perms)
+            .store_private_sequence(None, XorName::random(), 15000, owner, perms)
             .await?;
 
-        let data = client.get_sequence(address).await?;
-        assert_eq!(data.entries_index(), 0);
-        assert_eq!(data.owners_index(), 1);
-        assert_eq!(data.permissions_index(), 1);
+        // Appended by its owner, while our local replica doesn't have it
+        owner_client
+            .append_to_sequence(address, b"VALUE1".to_vec())
+            .await?;
+        assert!(client.sequence_cache.lock().await.contains(&address));
+        // Checked for being deleted once fetched long enough ago
+        clock.advance(SEQUENCE_DELETED_CHECK_INTERVAL);
 
-        let pub_permissions = client.get_sequence_pub_permissions(address).await?;
-        let user_perms = pub_permissions
-            .permissions
-            .get(&SequenceUser::Key(owner))
-            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
-        assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
-        assert_eq!(None, user_perms.is_allowed(SequenceAction::Append));
+        // Appended right after it, to the Sequence fetched for the deleted check
         assert_eq!(
-            Some(true),
-            user_perms.is_allowed(SequenceAction::ManagePermissions)
+            client
+                .append_to_sequence_indexed(address, b"VALUE2".to_vec())
+                .await?,
+            1
+        );
+        assert_eq!(
+            client
+                .get_sequence_range(
+                    address,
+                    (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0))
+                )
+                .await?,
+            vec![b"VALUE1".to_vec(), b"VALUE2".to_vec()]
         );
 
-        match client
-            .get_sequence_user_permissions(address, SequenceUser::Key(owner))
-            .await?
-        {
-            SequenceUserPermissions::Public(user_perms) => {
-                assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
-                assert_eq!(None, user_perms.is_allowed(SequenceAction::Append));
-                assert_eq!(
-                    Some(true),
-                    user_perms.is_allowed(SequenceAction::ManagePermissions)
-                );
+        Ok(())
+    }
+}
+
+#[allow(missing_docs)]
+#[cfg(any(test, feature = "simulated-payouts"))]
+mod tests {
+    #[cfg(test)]
+    use super::exported_tests;
+    #[cfg(test)]
+    use super::{with_deadline, CoreError};
+    #[cfg(test)]
+    use futures::future;
+    #[cfg(test)]
+    use safe_nd::Sequence;
+    #[cfg(test)]
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn sequence_read_is_sent_as_a_get_query() -> Result<(), CoreError> {
+        use crate::client::ClientConfig;
+        use crate::utils::test_utils::MockTransport;
+        use crate::Client;
+        use safe_nd::{
+            DataQuery, Message, PublicKey, Query, QueryResponse, SequenceAddress, SequenceRead,
+            TransferQuery,
+        };
+        use std::sync::Arc;
+        use threshold_crypto::SecretKeySet;
+        use xor_name::XorName;
+
+        let owner = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+        let sequence = Sequence::new_public(owner, XorName::random(), 15000);
+        let address = *sequence.address();
+        let replicas_pk_set = SecretKeySet::random(0, &mut rand::thread_rng()).public_keys();
+
+        let transport = Arc::new(MockTransport::new(move |query| match query {
+            Query::Transfer(TransferQuery::GetReplicaKeys(_)) => {
+                Ok(QueryResponse::GetReplicaKeys(Ok(replicas_pk_set.clone())))
             }
-            SequenceUserPermissions::Priv(_) => {
-                return Err(CoreError::from(
-                    "Unexpectedly obtained incorrect user permissions",
-                ))
+            Query::Data(DataQuery::Sequence(SequenceRead::Get(_))) => {
+                Ok(QueryResponse::GetSequence(Ok(sequence.clone())))
             }
-        }
+            _ => Err(CoreError::from("Unexpected query")),
+        }));
+        let mut client =
+            Client::with_transport(None, transport.clone(), ClientConfig::default()).await?;
+
+        assert_eq!(*client.get_sequence(address).await?.address(), address);
+
+        let is_get_query = |msg: &Message, expected: SequenceAddress| match msg {
+            Message::Query {
+                query: Query::Data(DataQuery::Sequence(SequenceRead::Get(address))),
+                ..
+            } => *address == expected,
+            _ => false,
+        };
+        let sent = transport.sent_messages();
+        // The replica keys were queried while setting up the client
+        assert_eq!(sent.len(), 2);
+        assert!(is_get_query(&sent[1], address));
 
-        let sim_client = gen_bls_keypair().public_key();
-        let mut perms2 = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
-        let _ = perms2.insert(
-            SequenceUser::Key(sim_client),
-            SequencePubUserPermissions::new(false, false),
-        );
-        client.sequence_set_pub_permissions(address, perms2).await?;
+        Ok(())
+    }
 
-        let pub_permissions = client.get_sequence_pub_permissions(address).await?;
-        let user_perms = pub_permissions
-            .permissions
-            .get(&SequenceUser::Key(sim_client))
-            .ok_or_else(|| CoreError::from("Unexpectedly failed to get user permissions"))?;
-        assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
-        assert_eq!(Some(false), user_perms.is_allowed(SequenceAction::Append));
-        assert_eq!(
-            Some(false),
-            user_perms.is_allowed(SequenceAction::ManagePermissions)
-        );
+    #[tokio::test]
+    async fn sequence_read_times_out_if_network_never_replies() -> Result<(), CoreError> {
+        let deadline = Duration::from_millis(100);
+        let start = Instant::now();
 
-        match client
-            .get_sequence_user_permissions(address, SequenceUser::Key(sim_client))
-            .await?
-        {
-            SequenceUserPermissions::Public(user_perms) => {
-                assert_eq!(Some(true), user_perms.is_allowed(SequenceAction::Read));
-                assert_eq!(Some(false), user_perms.is_allowed(SequenceAction::Append));
-                assert_eq!(
-                    Some(false),
-                    user_perms.is_allowed(SequenceAction::ManagePermissions)
-                );
-                Ok(())
-            }
-            SequenceUserPermissions::Priv(_) => Err(CoreError::from(
-                "Unexpectedly obtained incorrect user permissions",
-            )),
+        let res = with_deadline(deadline, future::pending::<Result<Sequence, CoreError>>()).await;
+
+        match res {
+            Err(CoreError::RequestTimeout) => (),
+            res => panic!("Unexpected result: {:?}", res),
         }
+        let elapsed = start.elapsed();
+        assert!(elapsed >= deadline);
+        assert!(elapsed < deadline * 10);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sequence_deletions_should_cost_put_price() -> Result<(), CoreError> {
+        exported_tests::sequence_deletions_should_cost_put_price().await
+    }
+
+    #[tokio::test]
+    async fn sequence_deletion_costs_write_price() -> Result<(), CoreError> {
+        exported_tests::sequence_deletion_costs_write_price().await
+    }
+
+    #[tokio::test]
+    async fn sequence_basics_test() -> Result<(), CoreError> {
+        exported_tests::sequence_basics_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_exists_test() -> Result<(), CoreError> {
+        exported_tests::sequence_exists_test().await
+    }
+
+    #[tokio::test]
+    async fn diff_sequence_test() -> Result<(), CoreError> {
+        exported_tests::diff_sequence_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_scope_test() -> Result<(), CoreError> {
+        exported_tests::sequence_scope_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_cache_eviction_test() -> Result<(), CoreError> {
+        exported_tests::sequence_cache_eviction_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_cache_fresh_hit_test() -> Result<(), CoreError> {
+        exported_tests::sequence_cache_fresh_hit_test().await
     }
 
-    pub async fn append_to_sequence_test() -> Result<(), CoreError> {
-        let name = XorName(rand::random());
-        let tag = 10;
-        let mut client = Client::new(None).await?;
+    #[tokio::test]
+    async fn sequence_no_cache_on_write_test() -> Result<(), CoreError> {
+        exported_tests::sequence_no_cache_on_write_test().await
+    }
 
-        let owner = client.public_key().await;
-        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
-        let _ = perms.insert(
-            SequenceUser::Key(owner),
-            SequencePubUserPermissions::new(true, true),
-        );
-        let address = client
-            .store_public_sequence(None, name, tag, owner, perms)
-            .await?;
+    #[tokio::test]
+    #[cfg(feature = "metrics")]
+    async fn sequence_cache_metrics_test() -> Result<(), CoreError> {
+        exported_tests::sequence_cache_metrics_test().await
+    }
 
-        client
-            .append_to_sequence(address, b"VALUE1".to_vec())
-            .await?;
+    #[tokio::test]
+    async fn sequence_cache_expired_refetch_test() -> Result<(), CoreError> {
+        exported_tests::sequence_cache_expired_refetch_test().await
+    }
 
-        let (index, data) = client.get_sequence_last_entry(address).await?;
-        assert_eq!(0, index);
-        assert_eq!(unwrap!(std::str::from_utf8(&data)), "VALUE1");
+    #[tokio::test]
+    async fn sequence_cache_zero_ttl_test() -> Result<(), CoreError> {
+        exported_tests::sequence_cache_zero_ttl_test().await
+    }
 
-        client
-            .append_to_sequence(address, b"VALUE2".to_vec())
-            .await?;
+    #[tokio::test]
+    async fn concurrent_appends_from_two_clients_test() -> Result<(), CoreError> {
+        exported_tests::concurrent_appends_from_two_clients_test().await
+    }
 
-        let (index, data) = client.get_sequence_last_entry(address).await?;
-        assert_eq!(1, index);
-        assert_eq!(unwrap!(std::str::from_utf8(&data)), "VALUE2");
+    #[tokio::test]
+    async fn append_if_index_test() -> Result<(), CoreError> {
+        exported_tests::append_if_index_test().await
+    }
 
-        let data = client
-            .get_sequence_range(
-                address,
-                (SequenceIndex::FromStart(0), SequenceIndex::FromEnd(0)),
-            )
-            .await?;
-        assert_eq!(unwrap!(std::str::from_utf8(&data[0])), "VALUE1");
-        assert_eq!(unwrap!(std::str::from_utf8(&data[1])), "VALUE2");
+    #[tokio::test]
+    async fn await_sequence_entry_confirmed_test() -> Result<(), CoreError> {
+        exported_tests::await_sequence_entry_confirmed_test().await
+    }
 
-        Ok(())
+    #[tokio::test]
+    async fn prefetch_sequences_test() -> Result<(), CoreError> {
+        exported_tests::prefetch_sequences_test().await
     }
 
-    pub async fn sequence_owner_test() -> Result<(), CoreError> {
-        let name = XorName(rand::random());
-        let tag = 10;
-        let mut client = Client::new(None).await?;
+    #[tokio::test]
+    async fn get_multi_sequence_ranges_test() -> Result<(), CoreError> {
+        exported_tests::get_multi_sequence_ranges_test().await
+    }
 
-        let owner = client.public_key().await;
-        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
-        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
-        let address = client
-            .store_private_sequence(None, name, tag, owner, perms)
-            .await?;
+    #[tokio::test]
+    async fn private_sequence_with_no_permissions_is_owner_only_test() -> Result<(), CoreError> {
+        exported_tests::private_sequence_with_no_permissions_is_owner_only_test().await
+    }
 
-        client
-            .append_to_sequence(address, b"VALUE1".to_vec())
-            .await?;
-        client
-            .append_to_sequence(address, b"VALUE2".to_vec())
-            .await?;
+    #[tokio::test]
+    async fn concurrent_sequence_reads_are_coalesced_test() -> Result<(), CoreError> {
+        exported_tests::concurrent_sequence_reads_are_coalesced_test().await
+    }
 
-        let data = client.get_sequence(address).await?;
-        assert_eq!(data.entries_index(), 2);
-        assert_eq!(data.owners_index(), 1);
-        assert_eq!(data.permissions_index(), 1);
+    #[tokio::test]
+    async fn get_sequence_timeout_cached_test() -> Result<(), CoreError> {
+        exported_tests::get_sequence_timeout_cached_test().await
+    }
 
-        let current_owner = client.get_sequence_owner(address).await?;
-        assert_eq!(owner, current_owner.public_key);
+    #[tokio::test]
+    async fn get_sequence_entry_test() -> Result<(), CoreError> {
+        exported_tests::get_sequence_entry_test().await
+    }
 
-        let sim_client = gen_bls_keypair().public_key();
-        client.sequence_set_owner(address, sim_client).await?;
+    #[tokio::test]
+    async fn get_sequence_entries_indexed_test() -> Result<(), CoreError> {
+        exported_tests::get_sequence_entries_indexed_test().await
+    }
 
-        let current_owner = client.get_sequence_owner(address).await?;
-        assert_eq!(sim_client, current_owner.public_key);
+    #[tokio::test]
+    async fn encrypted_private_sequence_test() -> Result<(), CoreError> {
+        exported_tests::encrypted_private_sequence_test().await
+    }
 
-        Ok(())
+    #[tokio::test]
+    async fn append_to_deleted_sequence_test() -> Result<(), CoreError> {
+        exported_tests::append_to_deleted_sequence_test().await
     }
 
-    pub async fn sequence_can_delete_private_test() -> Result<(), CoreError> {
-        let mut client = Client::new(None).await?;
+    #[tokio::test]
+    async fn append_to_cached_private_sequence_refreshes_it_test() -> Result<(), CoreError> {
+        exported_tests::append_to_cached_private_sequence_refreshes_it_test().await
+    }
 
-        let name = XorName(rand::random());
-        let tag = 15000;
-        let owner = client.public_key().await;
+    #[tokio::test]
+    async fn get_sequence_range_validation_test() -> Result<(), CoreError> {
+        exported_tests::get_sequence_range_validation_test().await
+    }
 
-        // store a Private Sequence
-        let mut perms = BTreeMap::<PublicKey, SequencePrivUserPermissions>::new();
-        let _ = perms.insert(owner, SequencePrivUserPermissions::new(true, true, true));
-        let address = client
-            .store_private_sequence(None, name, tag, owner, perms)
-            .await?;
-        let sequence = client.get_sequence(address).await?;
-        assert!(sequence.is_private());
+    #[tokio::test]
+    async fn get_sequence_at_version_test() -> Result<(), CoreError> {
+        exported_tests::get_sequence_at_version_test().await
+    }
 
-        client.delete_sequence(address).await?;
+    #[tokio::test]
+    async fn sequence_writes_fail_on_insufficient_balance_test() -> Result<(), CoreError> {
+        exported_tests::sequence_writes_fail_on_insufficient_balance_test().await
+    }
 
-        match client.get_sequence(address).await {
-            Err(CoreError::DataError(SndError::NoSuchData)) => Ok(()),
-            Err(err) => Err(CoreError::from(format!(
-                "Unexpected error returned when deleting a nonexisting Private Sequence: {}",
-                err
-            ))),
-            Ok(_res) => Err(CoreError::from(
-                "Unexpectedly retrieved a deleted Private Sequence!",
-            )),
-        }
+    #[tokio::test]
+    async fn append_entry_size_limit_test() -> Result<(), CoreError> {
+        exported_tests::append_entry_size_limit_test().await
     }
 
-    pub async fn sequence_cannot_delete_public_test() -> Result<(), CoreError> {
-        let mut client = Client::new(None).await?;
+    #[tokio::test]
+    async fn append_to_sequence_indexed_test() -> Result<(), CoreError> {
+        exported_tests::append_to_sequence_indexed_test().await
+    }
 
-        let name = XorName(rand::random());
-        let tag = 15000;
-        let owner = client.public_key().await;
+    #[tokio::test]
+    async fn append_to_sequence_nowait_test() -> Result<(), CoreError> {
+        exported_tests::append_to_sequence_nowait_test().await
+    }
 
-        // store a Public Sequence
-        let mut perms = BTreeMap::<SequenceUser, SequencePubUserPermissions>::new();
-        let _ = perms.insert(
-            SequenceUser::Anyone,
-            SequencePubUserPermissions::new(true, true),
-        );
-        let address = client
-            .store_public_sequence(None, name, tag, owner, perms)
-            .await?;
-        let sequence = client.get_sequence(address).await?;
-        assert!(sequence.is_pub());
+    #[tokio::test]
+    async fn sequence_mirror_test() -> Result<(), CoreError> {
+        exported_tests::sequence_mirror_test().await
+    }
 
-        client.delete_sequence(address).await?;
+    #[tokio::test]
+    async fn append_typed_test() -> Result<(), CoreError> {
+        exported_tests::append_typed_test().await
+    }
 
-        // Check that our data still exists.
-        match client.get_sequence(address).await {
-            Err(CoreError::DataError(SndError::InvalidOperation)) => Ok(()),
-            Err(err) => Err(CoreError::from(format!(
-                "Unexpected error returned when attempting to get a Public Sequence: {}",
-                err
-            ))),
-            Ok(_data) => Ok(()),
-        }
+    #[tokio::test]
+    async fn sequence_json_entries_test() -> Result<(), CoreError> {
+        exported_tests::sequence_json_entries_test().await
     }
-}
 
-#[allow(missing_docs)]
-#[cfg(any(test, feature = "simulated-payouts"))]
-mod tests {
-    #[cfg(test)]
-    use super::exported_tests;
-    #[cfg(test)]
-    use super::CoreError;
+    #[tokio::test]
+    async fn get_sequence_range_typed_mismatch_test() -> Result<(), CoreError> {
+        exported_tests::get_sequence_range_typed_mismatch_test().await
+    }
 
     #[tokio::test]
-    async fn sequence_deletions_should_cost_put_price() -> Result<(), CoreError> {
-        exported_tests::sequence_deletions_should_cost_put_price().await
+    async fn sequence_address_from_seed_test() -> Result<(), CoreError> {
+        exported_tests::sequence_address_from_seed_test().await
     }
 
     #[tokio::test]
-    async fn sequence_basics_test() -> Result<(), CoreError> {
-        exported_tests::sequence_basics_test().await
+    async fn sequence_force_refresh_test() -> Result<(), CoreError> {
+        exported_tests::sequence_force_refresh_test().await
+    }
+
+    #[tokio::test]
+    async fn can_user_append_test() -> Result<(), CoreError> {
+        exported_tests::can_user_append_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_transaction_test() -> Result<(), CoreError> {
+        exported_tests::sequence_transaction_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_permission_helpers_test() -> Result<(), CoreError> {
+        exported_tests::sequence_permission_helpers_test().await
     }
 
     #[tokio::test]
@@ -1185,28 +6652,158 @@ mod tests {
         exported_tests::sequence_private_permissions_test().await
     }
 
+    #[tokio::test]
+    async fn frozen_sequence_test() -> Result<(), CoreError> {
+        exported_tests::frozen_sequence_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_open_for_appends_is_not_frozen_test() -> Result<(), CoreError> {
+        exported_tests::sequence_open_for_appends_is_not_frozen_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_revoke_user_test() -> Result<(), CoreError> {
+        exported_tests::sequence_revoke_user_test().await
+    }
+
+    #[tokio::test]
+    async fn grant_sequence_users_test() -> Result<(), CoreError> {
+        exported_tests::grant_sequence_users_test().await
+    }
+
     #[tokio::test]
     async fn sequence_pub_permissions_test() -> Result<(), CoreError> {
         exported_tests::sequence_pub_permissions_test().await
     }
 
+    #[tokio::test]
+    async fn sequence_permissions_history_test() -> Result<(), CoreError> {
+        exported_tests::sequence_permissions_history_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_with_entries_test() -> Result<(), CoreError> {
+        exported_tests::sequence_with_entries_test().await
+    }
+
     #[tokio::test]
     async fn append_to_sequence_test() -> Result<(), CoreError> {
         exported_tests::append_to_sequence_test().await
     }
 
+    #[tokio::test]
+    async fn append_to_sequence_with_stale_permissions_test() -> Result<(), CoreError> {
+        exported_tests::append_to_sequence_with_stale_permissions_test().await
+    }
+
+    #[tokio::test]
+    async fn failed_append_is_not_kept_in_local_replica_test() -> Result<(), CoreError> {
+        exported_tests::failed_append_is_not_kept_in_local_replica_test().await
+    }
+
+    #[tokio::test]
+    async fn append_rejected_by_the_network_is_forgotten_test() -> Result<(), CoreError> {
+        exported_tests::append_rejected_by_the_network_is_forgotten_test().await
+    }
+
+    #[tokio::test]
+    async fn append_to_sequence_without_permission_test() -> Result<(), CoreError> {
+        exported_tests::append_to_sequence_without_permission_test().await
+    }
+
+    #[tokio::test]
+    async fn copy_sequence_test() -> Result<(), CoreError> {
+        exported_tests::copy_sequence_test().await
+    }
+
+    #[tokio::test]
+    async fn store_sequence_if_absent_test() -> Result<(), CoreError> {
+        exported_tests::store_sequence_if_absent_test().await
+    }
+
+    #[tokio::test]
+    async fn store_private_sequence_owner_only_test() -> Result<(), CoreError> {
+        exported_tests::store_private_sequence_owner_only_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_builder_test() -> Result<(), CoreError> {
+        exported_tests::sequence_builder_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_builder_validation_test() -> Result<(), CoreError> {
+        exported_tests::sequence_builder_validation_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_user_permissions_test() -> Result<(), CoreError> {
+        exported_tests::sequence_user_permissions_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_owner_and_permissions_getters_test() -> Result<(), CoreError> {
+        exported_tests::sequence_owner_and_permissions_getters_test().await
+    }
+
+    #[tokio::test]
+    async fn my_sequences_test() -> Result<(), CoreError> {
+        exported_tests::my_sequences_test().await
+    }
+
+    #[tokio::test]
+    async fn broadcast_sequence_test() -> Result<(), CoreError> {
+        exported_tests::broadcast_sequence_test().await
+    }
+
+    #[tokio::test]
+    async fn append_to_sequence_in_dry_run_test() -> Result<(), CoreError> {
+        exported_tests::append_to_sequence_in_dry_run_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_entries_count_test() -> Result<(), CoreError> {
+        exported_tests::sequence_entries_count_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_entry_stream_test() -> Result<(), CoreError> {
+        exported_tests::sequence_entry_stream_test().await
+    }
+
+    #[tokio::test]
+    async fn sequence_first_entry_test() -> Result<(), CoreError> {
+        exported_tests::sequence_first_entry_test().await
+    }
+
     #[tokio::test]
     async fn sequence_owner_test() -> Result<(), CoreError> {
         exported_tests::sequence_owner_test().await
     }
 
+    #[tokio::test]
+    async fn sequence_owner_history_test() -> Result<(), CoreError> {
+        exported_tests::sequence_owner_history_test().await
+    }
+
     #[tokio::test]
     async fn sequence_can_delete_private_test() -> Result<(), CoreError> {
         exported_tests::sequence_can_delete_private_test().await
     }
 
+    #[tokio::test]
+    async fn sequence_deletion_invalidates_cache_test() -> Result<(), CoreError> {
+        exported_tests::sequence_deletion_invalidates_cache_test().await
+    }
+
     #[tokio::test]
     async fn sequence_cannot_delete_public_test() -> Result<(), CoreError> {
         exported_tests::sequence_cannot_delete_public_test().await
     }
+
+    #[tokio::test]
+    async fn sequence_cannot_delete_private_of_another_owner_test() -> Result<(), CoreError> {
+        exported_tests::sequence_cannot_delete_private_of_another_owner_test().await
+    }
 }
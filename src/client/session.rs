@@ -0,0 +1,185 @@
+// Copyright 2020 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::{Client, ClientConfig};
+use crate::errors::CoreError;
+use bincode::{deserialize, serialize};
+use log::trace;
+use safe_nd::{PublicKey, ReplicaEvent, Sequence, SequenceAddress};
+use serde::{Deserialize, Serialize};
+use std::{sync::PoisonError, time::Duration};
+use threshold_crypto::SecretKey;
+
+// State of a client which can be restored on another one, without its secret key.
+#[derive(Serialize, Deserialize)]
+struct Session {
+    public_key: PublicKey,
+    transfer_history_version: usize,
+    transfer_history: Vec<ReplicaEvent>,
+    sequences: Vec<Sequence>,
+    created_sequences: Vec<SequenceAddress>,
+}
+
+impl Client {
+    /// Export the state of this client's session: the transfer history its balance was
+    /// computed from, the Sequences in its local CRDT replica, and the addresses of those
+    /// it stored, as listed by `my_sequences`.
+    ///
+    /// The secret key isn't part of the export, which can be restored with `Client::import_session`
+    /// to resume the session without fetching the transfer history again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use threshold_crypto::SecretKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let secret_key = SecretKey::random();
+    /// let client = Client::new(Some(secret_key.clone())).await?;
+    /// let session = client.export_session().await?;
+    ///
+    /// let restored = Client::import_session(&session, secret_key).await?;
+    /// assert_eq!(restored.get_local_balance().await, client.get_local_balance().await);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn export_session(&self) -> Result<Vec<u8>, CoreError> {
+        let sequences = self
+            .sequence_cache
+            .lock()
+            .await
+            .iter()
+            .map(|(_, (sequence, _))| sequence.clone())
+            .collect();
+
+        let session = Session {
+            public_key: self.public_key,
+            transfer_history_version: *self.transfer_history_version.lock().await,
+            transfer_history: self.transfer_history.lock().await.clone(),
+            sequences,
+            created_sequences: self.my_sequences(),
+        };
+
+        Ok(serialize(&session)?)
+    }
+
+    /// Create a client resuming a session exported with `export_session`, for the same secret key.
+    ///
+    /// The balance is computed from the transfer history of the session rather than fetched from
+    /// the network, and its Sequences are put in the local CRDT replica as if just fetched.
+    /// Fails with `CoreError::SessionKeyMismatch` should the session be for another key.
+    pub async fn import_session(session: &[u8], secret_key: SecretKey) -> Result<Self, CoreError> {
+        let session: Session = deserialize(session)?;
+        if session.public_key != PublicKey::from(secret_key.public_key()) {
+            return Err(CoreError::SessionKeyMismatch {
+                session_key: session.public_key,
+            });
+        }
+
+        let mut client =
+            Self::connect(Some(secret_key), Vec::new(), false, ClientConfig::default()).await?;
+        trace!(
+            "Importing session with {} transfer events and {} Sequences",
+            session.transfer_history.len(),
+            session.sequences.len()
+        );
+
+        client
+            .sync_actor_with_history(session.transfer_history.clone())
+            .await?;
+        *client.transfer_history_version.lock().await = session.transfer_history_version;
+        *client.transfer_history.lock().await = session.transfer_history;
+        *client
+            .created_sequences
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = session.created_sequences;
+
+        if client.sequence_cache_ttl > Duration::from_secs(0) {
+            let now = client.clock.now();
+            let mut cache = client.sequence_cache.lock().await;
+            for sequence in session.sequences {
+                let _ = cache.put(*sequence.address(), (sequence, now));
+            }
+        }
+
+        //Start listening for Events
+        client.listen_on_network().await;
+
+        Ok(client)
+    }
+}
+
+#[allow(missing_docs)]
+#[cfg(any(test, feature = "simulated-payouts", feature = "testing"))]
+pub mod exported_tests {
+    use super::*;
+    use safe_nd::Money;
+    use std::str::FromStr;
+    use xor_name::XorName;
+
+    pub async fn session_can_be_exported_and_imported() -> Result<(), CoreError> {
+        let secret_key = SecretKey::random();
+        let mut client = Client::new(Some(secret_key.clone())).await?;
+
+        // Give the client some history and money to pay for a Sequence
+        let mut payer = Client::new(None).await?;
+        payer
+            .send_money(client.public_key().await, Money::from_str("5")?)
+            .await?;
+        client.get_history().await?;
+
+        let address = client
+            .store_private_sequence_owner_only(None, XorName::random(), 15000)
+            .await?;
+
+        let session = client.export_session().await?;
+        let mut restored = Client::import_session(&session, secret_key).await?;
+
+        // Neither the balance nor the Sequence are fetched from the network
+        let queries_sent = restored.connection_manager.queries_sent();
+        assert_eq!(
+            restored.get_local_balance().await,
+            client.get_local_balance().await
+        );
+        let _ = restored.get_sequence(address).await?;
+        assert_eq!(restored.connection_manager.queries_sent(), queries_sent);
+        assert_eq!(restored.my_sequences(), vec![address]);
+
+        Ok(())
+    }
+
+    pub async fn session_cannot_be_imported_with_another_key() -> Result<(), CoreError> {
+        let client = Client::new(None).await?;
+        let session = client.export_session().await?;
+
+        match Client::import_session(&session, SecretKey::random()).await {
+            Err(CoreError::SessionKeyMismatch { session_key }) => {
+                assert_eq!(session_key, client.public_key().await);
+                Ok(())
+            }
+            res => panic!("Unexpected result: {:?}", res.map(|_| ())),
+        }
+    }
+}
+
+#[allow(missing_docs)]
+#[cfg(all(test, feature = "simulated-payouts"))]
+mod tests {
+    use super::exported_tests;
+    use crate::CoreError;
+
+    #[tokio::test]
+    async fn session_can_be_exported_and_imported() -> Result<(), CoreError> {
+        exported_tests::session_can_be_exported_and_imported().await
+    }
+
+    #[tokio::test]
+    async fn session_cannot_be_imported_with_another_key() -> Result<(), CoreError> {
+        exported_tests::session_cannot_be_imported_with_another_key().await
+    }
+}
@@ -1,13 +1,31 @@
 use safe_nd::{
-    Cmd, DebitAgreementProof, Event, Money, PublicKey, Query, QueryResponse, TransferCmd,
-    TransferQuery,
+    Cmd, DebitAgreementProof, Error as SndError, Event, Message, Money, PublicKey, Query,
+    QueryResponse, ReplicaEvent, Transfer, TransferCmd, TransferId, TransferQuery,
 };
 use safe_transfers::{ActorEvent, TransferInitiated};
 
-use crate::client::Client;
+use crate::client::{
+    BalanceBreakdown, Client, IncomingTransfer, SentTransfer, TransferStatus,
+    MAX_TRANSFER_MEMO_SIZE,
+};
 use crate::errors::CoreError;
 
-use log::{debug, info, trace};
+use futures::{
+    future::{self, Either, Future, FutureExt},
+    stream::{Stream, StreamExt},
+};
+use log::{debug, info, trace, warn};
+use std::time::Duration;
+use tokio::{sync::broadcast::TryRecvError, time};
+use tracing::{field, info_span, Instrument, Span};
+
+/// Interval at which the transfer history is retrieved while awaiting a credit.
+const CREDIT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// How many times registering a validated transfer is sent again after failing because the
+/// connection was lost or the request timed out.
+const REGISTER_TRANSFER_RETRIES: usize = 3;
+/// Delay before the first retry of registering a transfer, doubling with every retry.
+const REGISTER_TRANSFER_RETRY_BACKOFF: Duration = Duration::from_millis(500);
 
 /// Handle all Money transfers and Write API requests for a given ClientId.
 impl Client {
@@ -33,6 +51,268 @@ impl Client {
         self.transfer_actor.lock().await.balance()
     }
 
+    /// Get the balance from the local actor, along with the Money sent in transfers which
+    /// are still awaiting validation or registration. (ie. Without querying the network)
+    ///
+    /// The local actor stays locked while the pending transfers are summed, so the figures
+    /// are consistent with each other even while transfers are being sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use std::str::FromStr;
+    /// use safe_nd::Money;
+    /// # #[tokio::main]async fn main() {let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// let breakdown = client.get_balance_breakdown().await;
+    /// assert_eq!(breakdown.pending_out, Money::from_str("0")?);
+    /// assert_eq!(breakdown.available, breakdown.settled);
+    /// # Ok(())} );}
+    /// ```
+    pub async fn get_balance_breakdown(&self) -> BalanceBreakdown {
+        info!("Retrieving actor's balance breakdown.");
+        let actor = self.transfer_actor.lock().await;
+        let settled = actor.balance();
+
+        let awaiting_validation = self
+            .transfers_awaiting_validation
+            .lock()
+            .await
+            .values()
+            .fold(0, |sum, amount| sum + amount.as_nano());
+        let awaiting_registration = self
+            .pending_transfers
+            .lock()
+            .await
+            .values()
+            .fold(0, |sum, proof| sum + proof.amount().as_nano());
+        let pending_out = Money::from_nano(awaiting_validation + awaiting_registration);
+
+        BalanceBreakdown {
+            settled,
+            pending_out,
+            available: Money::from_nano(settled.as_nano().saturating_sub(pending_out.as_nano())),
+        }
+    }
+
+    /// Get the transfers debited from our balance known to the local actor, from the given
+    /// version onwards, oldest first. (ie. Without querying the network)
+    ///
+    /// # Examples
+    ///
+    /// List the transfers sent by a client
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main]async fn main() {let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// for transfer in client.get_debits(0).await {
+    ///     println!("Sent {} to {:?}", transfer.amount, transfer.to);
+    /// }
+    /// # Ok(())} );}
+    /// ```
+    pub async fn get_debits(&self, since_version: u64) -> Vec<Transfer> {
+        trace!("Retrieving actor's debits since version {}", since_version);
+        self.transfer_actor
+            .lock()
+            .await
+            .debits_since(since_version as usize)
+    }
+
+    /// Get the transfers debited from our balance as with `get_debits`, each along with the memo
+    /// it was sent with by this client, if any. (ie. Without querying the network)
+    ///
+    /// # Examples
+    ///
+    /// List the references of the transfers sent by a client
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main]async fn main() {let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// for sent in client.get_sent_transfers(0).await {
+    ///     if let Some(memo) = sent.memo {
+    ///         println!("Sent {} for {:?}", sent.transfer.amount, String::from_utf8_lossy(&memo));
+    ///     }
+    /// }
+    /// # Ok(())} );}
+    /// ```
+    pub async fn get_sent_transfers(&self, since_version: u64) -> Vec<SentTransfer> {
+        let debits = self.get_debits(since_version).await;
+        let memos = self.transfer_memos.lock().await;
+
+        debits
+            .into_iter()
+            .map(|transfer| SentTransfer {
+                memo: memos.get(&transfer.id).cloned(),
+                transfer,
+            })
+            .collect()
+    }
+
+    /// Subscribe to the changes of the local actor's balance.
+    ///
+    /// The returned stream yields the new local balance every time a transfer is
+    /// validated, registered or synched from the network history. Each subscriber only
+    /// gets the changes which happened after subscribing, and a subscriber which falls
+    /// too far behind skips the oldest changes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use futures::stream::StreamExt;
+    /// use safe_nd::{Money, PublicKey};
+    /// use std::str::FromStr;
+    /// # #[tokio::main]async fn main() {let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.trigger_simulated_farming_payout(Money::from_str("100")?).await?;
+    /// let mut balance_changes = Box::pin(client.subscribe_balance_changes().await);
+    ///
+    /// client.send_money(pk, Money::from_str("1")?).await?;
+    ///
+    /// let _new_balance = balance_changes.next().await;
+    /// # Ok(())} );}
+    /// ```
+    pub async fn subscribe_balance_changes(&self) -> impl Stream<Item = Money> {
+        self.balance_change_sender
+            .subscribe()
+            .filter_map(|res| async move { res.ok() })
+    }
+
+    /// Subscribe to the transfers of Money credited to this client.
+    ///
+    /// The returned stream yields the sender and amount of each credit not seen before, as the
+    /// transfer history is retrieved from the network (e.g. by `get_history`, or before sending
+    /// money). Memos are only kept by their sender, so aren't part of it.
+    /// Each subscriber only gets the transfers received after subscribing, and a subscriber
+    /// which falls too far behind skips the oldest ones.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use futures::stream::StreamExt;
+    /// use safe_nd::Money;
+    /// use std::str::FromStr;
+    /// # #[tokio::main]async fn main() {let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut sender = Client::new(None).await?;
+    /// let mut recipient = Client::new(None).await?;
+    /// let mut incoming = Box::pin(recipient.incoming_transfers().await);
+    ///
+    /// sender.send_money(recipient.public_key().await, Money::from_str("1")?).await?;
+    /// recipient.get_history().await?;
+    ///
+    /// let _transfer = incoming.next().await;
+    /// # Ok(())} );}
+    /// ```
+    pub async fn incoming_transfers(&self) -> impl Stream<Item = IncomingTransfer> {
+        self.incoming_transfer_sender
+            .subscribe()
+            .filter_map(|res| async move { res.ok() })
+    }
+
+    /// Wait until this client is credited at least `min_amount` in a single transfer from the
+    /// given key, returning the amount credited, or fail with `CoreError::RequestTimeout` once
+    /// the timeout elapses.
+    ///
+    /// Only the credits not seen before by this client count. As the network doesn't push credits
+    /// to their recipient, the transfer history is retrieved meanwhile, every
+    /// `CREDIT_POLL_INTERVAL`, which notifies them as with `incoming_transfers`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::Money;
+    /// use std::{str::FromStr, time::Duration};
+    /// # #[tokio::main]async fn main() {let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut payer = Client::new(None).await?;
+    /// let mut service = Client::new(None).await?;
+    ///
+    /// payer.send_money(service.public_key().await, Money::from_str("1")?).await?;
+    ///
+    /// let paid = service
+    ///     .await_credit_from(payer.public_key().await, Money::from_str("1")?, Duration::from_secs(10))
+    ///     .await?;
+    /// # Ok(())} );}
+    /// ```
+    pub async fn await_credit_from(
+        &mut self,
+        from: PublicKey,
+        min_amount: Money,
+        timeout: Duration,
+    ) -> Result<Money, CoreError> {
+        trace!(
+            "Awaiting a credit of at least {} from {:?}",
+            min_amount,
+            from
+        );
+        let mut incoming = self.incoming_transfer_sender.subscribe();
+
+        let wait = async {
+            loop {
+                let version = *self.transfer_history_version.lock().await;
+                let _ = self.get_history_since(version).await?;
+
+                // Go through the credits the history just notified
+                loop {
+                    match incoming.try_recv() {
+                        Ok(transfer)
+                            if transfer.sender == from && transfer.amount >= min_amount =>
+                        {
+                            return Ok(transfer.amount)
+                        }
+                        Ok(_) | Err(TryRecvError::Lagged(_)) => continue,
+                        Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => break,
+                    }
+                }
+
+                time::delay_for(CREDIT_POLL_INTERVAL).await;
+            }
+        };
+
+        match time::timeout(timeout, wait).await {
+            Ok(res) => res,
+            Err(_) => Err(CoreError::RequestTimeout),
+        }
+    }
+
+    /// Notify the incoming transfer subscribers of the credits to this client
+    /// found among newly retrieved history events.
+    pub(crate) fn notify_incoming_transfers(&self, events: &[ReplicaEvent]) {
+        for event in events {
+            if let ReplicaEvent::TransferPropagated(propagated) = event {
+                let proof = &propagated.debit_proof;
+                if proof.to() != self.public_key {
+                    continue;
+                }
+
+                debug!("Incoming transfer {:?} of {}", proof.id(), proof.amount());
+                // It's fine if there are no subscribers
+                let _ = self.incoming_transfer_sender.send(IncomingTransfer {
+                    id: proof.id(),
+                    sender: proof.from(),
+                    amount: proof.amount(),
+                });
+            }
+        }
+    }
+
+    /// Notify the balance change subscribers of a new local balance,
+    /// which also makes the cached network balance stale.
+    pub(crate) async fn notify_balance_change(&self, balance: Money) {
+        self.invalidate_balance_cache().await;
+        // It's fine if there are no subscribers
+        let _ = self.balance_change_sender.send(balance);
+    }
+
     /// Handle a validation event.
     pub(crate) async fn handle_validation_event(
         &mut self,
@@ -64,6 +344,7 @@ impl Client {
         actor.apply(ActorEvent::TransferValidationReceived(
             transfer_validation.clone(),
         ))?;
+        self.notify_balance_change(actor.balance()).await;
 
         Ok(transfer_validation.proof)
     }
@@ -79,11 +360,9 @@ impl Client {
 
         let msg_contents = Query::Transfer(TransferQuery::GetBalance(public_key));
 
-        let message = Self::create_query_message(msg_contents);
-
-        match self.connection_manager.send_query(&message).await? {
+        match self.send_query(msg_contents).await? {
             QueryResponse::GetBalance(balance) => balance.map_err(CoreError::from),
-            _ => Err(CoreError::from("Unexpected response when querying balance")),
+            other => Err(CoreError::unexpected_query_response("GetBalance", &other)),
         }
     }
 
@@ -120,16 +399,590 @@ impl Client {
     /// # Ok(()) } ); }
     /// ```
     pub async fn send_money(&mut self, to: PublicKey, amount: Money) -> Result<(), CoreError> {
+        let span = info_span!(
+            "send_money",
+            to = ?to,
+            amount = ?amount,
+            transfer_id = field::Empty
+        );
+        async {
+            let _ = self.transfer_money(to, amount).await?;
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Send all the Money available to spend to another PublicKey, returning the amount sent.
+    ///
+    /// The amount is what `get_balance_breakdown` reports as available, once the local actor is up
+    /// to date with the network, so Money still tied up in pending transfers is never sent again.
+    /// Transfers aren't charged any fee. When there's nothing to send no transfer is made, and
+    /// zero is returned.
+    ///
+    /// # Examples
+    ///
+    /// Empty a balance into another key
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.trigger_simulated_farming_payout(Money::from_str("100")?).await?;
+    ///
+    /// let sent = client.send_all(pk).await?;
+    /// assert_eq!(client.get_local_balance().await, Money::from_str("0")?);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn send_all(&mut self, to: PublicKey) -> Result<Money, CoreError> {
+        info!("Sending all available money");
+        self.check_writes_allowed()?;
+
+        // Nothing else can be debited between computing the amount and sending it
+        let debit_lock = self.debit_lock.clone();
+        let _debit_guard = debit_lock.lock().await;
+
+        let version = *self.transfer_history_version.lock().await;
+        let _ = self.get_history_since(version).await?;
+        self.complete_cancelled_transfers().await?;
+
+        let amount = self.get_balance_breakdown().await.available;
+        if amount == Money::from_nano(0) {
+            info!("No money available to send");
+            return Ok(amount);
+        }
+
+        let _ = self.send_synced_transfer(to, amount).await?;
+        Ok(amount)
+    }
+
+    /// Send money to another PublicKey, unless `cancel` completes before the transfer is validated.
+    ///
+    /// Once cancelled, `CoreError::OperationAborted` is returned, and the balance is as before
+    /// the send. A transfer cancelled before being sent for validation is dropped. One cancelled
+    /// while awaiting its validation may still be validated by the Elders, which then hold it as
+    /// our next debit, so it's kept pending instead (see `get_transfer_status`): should its
+    /// validation arrive, it's registered in the background, and the next payment or transfer
+    /// of this client waits for it first.
+    ///
+    /// # Examples
+    ///
+    /// Abort a transfer when the user closes the payment dialog
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use futures::{channel::oneshot, FutureExt};
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.trigger_simulated_farming_payout(Money::from_str("100")?).await?;
+    ///
+    /// let (close_dialog, dialog_closed) = oneshot::channel::<()>();
+    /// # drop(close_dialog);
+    /// match client.send_money_cancellable(pk, Money::from_str("10")?, dialog_closed.map(|_| ())).await {
+    ///     Err(CoreError::OperationAborted) => assert_eq!(client.get_local_balance().await, Money::from_str("100")?),
+    ///     res => res?,
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn send_money_cancellable<C>(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+        cancel: C,
+    ) -> Result<(), CoreError>
+    where
+        C: Future<Output = ()>,
+    {
+        info!("Sending money, until cancelled");
+        self.check_writes_allowed()?;
+
+        let debit_lock = self.debit_lock.clone();
+        let _debit_guard = debit_lock.lock().await;
+
+        let version = *self.transfer_history_version.lock().await;
+        let _ = self.get_history_since(version).await?;
+
+        if self.dry_run {
+            let _ = self.dry_run_transfer(to, amount).await?;
+            return Ok(());
+        }
+
+        let debit_proof = self.validate_transfer_or_cancel(to, amount, cancel).await?;
+        self.register_pending_transfer(debit_proof).await
+    }
+
+    /// Send money to another PublicKey, attaching a memo to the transfer.
+    ///
+    /// The memo can't be larger than `MAX_TRANSFER_MEMO_SIZE` bytes. It is kept by this client
+    /// against the returned transfer id, and can be retrieved with `get_transfer_memo`, or
+    /// along with the transfer from our history with `get_sent_transfers`.
+    ///
+    /// # Examples
+    ///
+    /// Send money to a PublickKey, with a reference for the recipient.
+    /// (This test uses "simulated payouts" to generate test money. This of course would not be avaiable on a live network.)
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// // A random sk, to send money to
+    /// let sk = threshold_crypto::SecretKey::random();
+    /// let pk = PublicKey::from(sk.public_key());
+    /// // Next we create a random client.
+    /// let mut client = Client::new(None).await?;
+    /// let target_balance = Money::from_str("100")?;
+    /// // And trigger a simulated payout to our client's PublicKey, so we have money to send.
+    /// let _ = client.trigger_simulated_farming_payout(target_balance).await?;
+    ///
+    /// let id = client.send_money_with_memo(pk, target_balance, b"invoice #1".to_vec()).await?;
+    ///
+    /// assert_eq!(client.get_transfer_memo(id).await, Some(b"invoice #1".to_vec()));
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn send_money_with_memo(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+        memo: Vec<u8>,
+    ) -> Result<TransferId, CoreError> {
+        if memo.len() > MAX_TRANSFER_MEMO_SIZE {
+            return Err(CoreError::from(format!(
+                "Transfer memo of {} bytes exceeds the maximum of {} bytes",
+                memo.len(),
+                MAX_TRANSFER_MEMO_SIZE
+            )));
+        }
+
+        let id = self.transfer_money(to, amount).await?;
+        let _ = self.transfer_memos.lock().await.insert(id, memo);
+
+        Ok(id)
+    }
+
+    /// Get the memo attached to a transfer sent by this client with `send_money_with_memo`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::PublicKey;
+    /// use crdts::Dot;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let sk = threshold_crypto::SecretKey::random();
+    /// let client = Client::new(None).await?;
+    /// let unknown_transfer = Dot::new(PublicKey::from(sk.public_key()), 1);
+    ///
+    /// assert_eq!(client.get_transfer_memo(unknown_transfer).await, None);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_transfer_memo(&self, id: TransferId) -> Option<Vec<u8>> {
+        self.transfer_memos.lock().await.get(&id).cloned()
+    }
+
+    /// Send money to another PublicKey, guarding against sending it more than once.
+    ///
+    /// When an `idempotency_key` is provided, the client keeps track of it: while the transfer
+    /// is in flight any other attempt with the same key fails, and once the transfer has been
+    /// registered any retry with that key returns the id of the already completed transfer,
+    /// without validating nor sending any money again. If the transfer fails the key is
+    /// released, so the transfer can be retried with it.
+    ///
+    /// Keys are only tracked by this client (and its clones), and aren't persisted. Completed
+    /// transfers are remembered rather than forgotten once registered, so a retry after success
+    /// still finds them, but only the last `TRANSFER_IDEMPOTENCY_KEYS_CAPACITY` keys are kept:
+    /// retrying with a key forgotten since sends the money again.
+    /// Without an `idempotency_key` this behaves just like `send_money`.
+    ///
+    /// # Examples
+    ///
+    /// Send money to a PublickKey only once
+    /// (This test uses "simulated payouts" to generate test money. This of course would not be avaiable on a live network.)
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.trigger_simulated_farming_payout(Money::from_str("100")?).await?;
+    /// let key = b"payment-1".to_vec();
+    ///
+    /// let id = client.send_money_with_idempotency_key(pk, Money::from_str("10")?, Some(key.clone())).await?;
+    /// // Retrying doesn't send the money again
+    /// let retry_id = client.send_money_with_idempotency_key(pk, Money::from_str("10")?, Some(key)).await?;
+    ///
+    /// assert_eq!(id, retry_id);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn send_money_with_idempotency_key(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+        idempotency_key: Option<Vec<u8>>,
+    ) -> Result<TransferId, CoreError> {
+        let key = match idempotency_key {
+            Some(key) => key,
+            None => return self.transfer_money(to, amount).await,
+        };
+
+        {
+            let mut keys = self.transfer_idempotency_keys.lock().await;
+            match keys.get(&key) {
+                Some(Some(id)) => {
+                    info!("Transfer with idempotency key already completed: {:?}", id);
+                    return Ok(*id);
+                }
+                Some(None) => {
+                    return Err(CoreError::from(
+                        "A transfer with this idempotency key is already in progress",
+                    ))
+                }
+                None => {
+                    // Mark the key as in flight
+                    let _ = keys.put(key.clone(), None);
+                }
+            }
+        }
+
+        match self.transfer_money(to, amount).await {
+            Ok(id) => {
+                let _ = self
+                    .transfer_idempotency_keys
+                    .lock()
+                    .await
+                    .put(key, Some(id));
+                Ok(id)
+            }
+            Err(error) => {
+                let _ = self.transfer_idempotency_keys.lock().await.pop(&key);
+                Err(error)
+            }
+        }
+    }
+
+    /// Send money to several PublicKeys.
+    ///
+    /// The balance history is only retrieved once before sending any money, and the payments
+    /// are then sent one after the other. The returned `Vec` holds the outcome of each payment,
+    /// in the same order they were given, so a partially successful batch can be identified.
+    ///
+    /// If the sum of all payments exceeds the client's balance no money is sent at all, and an
+    /// `InsufficientBalance` error is returned.
+    ///
+    /// # Examples
+    ///
+    /// Send money to several PublickKeys.
+    /// (This test uses "simulated payouts" to generate test money. This of course would not be avaiable on a live network.)
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{PublicKey, Money};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk1 = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let pk2 = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.trigger_simulated_farming_payout(Money::from_str("100")?).await?;
+    ///
+    /// let results = client
+    ///     .send_money_batch(vec![(pk1, Money::from_str("10")?), (pk2, Money::from_str("20")?)])
+    ///     .await?;
+    ///
+    /// assert!(results.iter().all(|res| res.is_ok()));
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn send_money_batch(
+        &mut self,
+        payments: Vec<(PublicKey, Money)>,
+    ) -> Result<Vec<Result<(), CoreError>>, CoreError> {
+        info!("Sending a batch of {} payments", payments.len());
+        self.check_writes_allowed()?;
+
+        // No other transfer can be debited from our actor until the whole batch is sent
+        let debit_lock = self.debit_lock.clone();
+        let _debit_guard = debit_lock.lock().await;
+
+        // first make sure our balance history is up to date, just once for all payments
+        let version = *self.transfer_history_version.lock().await;
+        let _ = self.get_history_since(version).await?;
+
+        let total = payments
+            .iter()
+            .try_fold(Money::from_nano(0), |total, (_, amount)| {
+                total.checked_add(*amount)
+            })
+            .ok_or_else(|| CoreError::from("Overflow when adding up the batch of payments"))?;
+
+        if total > self.get_local_balance().await {
+            return Err(CoreError::from(SndError::InsufficientBalance));
+        }
+
+        let mut results = Vec::with_capacity(payments.len());
+        for (to, amount) in payments {
+            let res = self.send_synced_transfer(to, amount).await.map(|_| ());
+            results.push(res);
+        }
+
+        Ok(results)
+    }
+
+    // Transfer money to another PublicKey, returning the id of the transfer.
+    async fn transfer_money(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+    ) -> Result<TransferId, CoreError> {
         info!("Sending money");
+        self.check_writes_allowed()?;
+
+        // Transfers from our clones wait for this one to be registered, so the actor's
+        // debits are made in order on top of each other
+        let debit_lock = self.debit_lock.clone();
+        let _debit_guard = debit_lock.lock().await;
+
+        // first make sure our balance history is up to date, fetching only what we don't know yet
+        let version = *self.transfer_history_version.lock().await;
+        let _ = self.get_history_since(version).await?;
 
-        // first make sure our balance  history is up to date
-        self.get_history().await?;
+        self.send_synced_transfer(to, amount).await
+    }
 
-        println!(
-            "Debits form our actor at send: {:?}",
-            self.transfer_actor.lock().await.debits_since(0)
+    // Transfer money to another PublicKey, assuming the local actor is already up to date
+    // with the network's history. Returns the id of the transfer.
+    async fn send_synced_transfer(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+    ) -> Result<TransferId, CoreError> {
+        if self.dry_run {
+            return self.dry_run_transfer(to, amount).await;
+        }
+
+        let debit_proof = self.validate_transfer(to, amount).await?;
+        let transfer_id = debit_proof.id();
+        let _ = Span::current().record("transfer_id", &field::debug(transfer_id));
+
+        self.register_pending_transfer(debit_proof).await?;
+
+        Ok(transfer_id)
+    }
+
+    // Sign the transfer, but neither apply it to our actor nor send it to the network
+    async fn dry_run_transfer(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+    ) -> Result<TransferId, CoreError> {
+        self.check_writes_allowed()?;
+
+        let signed_transfer = self
+            .transfer_actor
+            .lock()
+            .await
+            .transfer(amount, to)?
+            .ok_or_else(|| CoreError::from("No transfer generated by the actor."))?
+            .signed_transfer;
+
+        let msg_contents = Cmd::Transfer(TransferCmd::ValidateTransfer(signed_transfer.clone()));
+        let message = Self::create_cmd_message(msg_contents);
+        info!("Dry run, not sending transfer: {:?}", message);
+
+        Ok(signed_transfer.id())
+    }
+
+    /// Get the transfers which have been validated by the network but not yet registered.
+    ///
+    /// Should the registration of a validated transfer fail, or the process stop before it happens,
+    /// the debit proof is kept here so it can be persisted and later completed with
+    /// `register_pending_transfer`.
+    ///
+    /// # Examples
+    ///
+    /// Check for transfers left pending
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// for proof in client.pending_transfers().await {
+    ///     client.register_pending_transfer(proof).await?;
+    /// }
+    /// assert!(client.pending_transfers().await.is_empty());
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn pending_transfers(&self) -> Vec<DebitAgreementProof> {
+        self.pending_transfers
+            .lock()
+            .await
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Get the status of a transfer, sent or received by this client.
+    ///
+    /// Transfers this client is still waiting on are reported from its local state. Otherwise
+    /// the transfer history is retrieved from the network, where registered transfers are found,
+    /// and any transfer found nowhere is `TransferStatus::Unknown`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio;use safe_core::CoreError;
+    /// use safe_core::client::TransferStatus;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, PublicKey};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    /// let mut client = Client::new(None).await?;
+    /// let _ = client.trigger_simulated_farming_payout(Money::from_str("100")?).await?;
+    ///
+    /// let id = client.send_money_with_idempotency_key(pk, Money::from_str("1")?, None).await?;
+    ///
+    /// assert_eq!(client.get_transfer_status(id).await?, TransferStatus::Registered);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_transfer_status(
+        &mut self,
+        id: TransferId,
+    ) -> Result<TransferStatus, CoreError> {
+        if self
+            .transfers_awaiting_validation
+            .lock()
+            .await
+            .contains_key(&id)
+            || self.cancelled_transfers.lock().await.contains_key(&id)
+        {
+            return Ok(TransferStatus::Pending);
+        }
+        if self.pending_transfers.lock().await.contains_key(&id)
+            || self.debit_cache.lock().await.contains_key(&id)
+        {
+            return Ok(TransferStatus::Validated);
+        }
+
+        let version = *self.transfer_history_version.lock().await;
+        let _ = self.get_history_since(version).await?;
+
+        let registered = self
+            .transfer_history
+            .lock()
+            .await
+            .iter()
+            .any(|event| match event {
+                ReplicaEvent::TransferRegistered(registered) => registered.debit_proof.id() == id,
+                ReplicaEvent::TransferPropagated(propagated) => propagated.debit_proof.id() == id,
+                _ => false,
+            });
+
+        if registered {
+            Ok(TransferStatus::Registered)
+        } else {
+            Ok(TransferStatus::Unknown)
+        }
+    }
+
+    /// Register on the network a transfer which was validated but not registered yet,
+    /// and apply it to our local actor.
+    ///
+    /// Should sending the registration fail because the connection was lost or the request timed
+    /// out, it's sent again after a backoff, up to `REGISTER_TRANSFER_RETRIES` times. Once they're
+    /// exhausted the transfer stays in `pending_transfers`, to be registered later.
+    ///
+    /// # Examples
+    ///
+    /// Complete a transfer from a persisted debit proof
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// # let persisted_proofs = client.pending_transfers().await;
+    /// // `persisted_proofs` were previously obtained from `pending_transfers`
+    /// for proof in persisted_proofs {
+    ///     client.register_pending_transfer(proof).await?;
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn register_pending_transfer(
+        &mut self,
+        debit_proof: DebitAgreementProof,
+    ) -> Result<(), CoreError> {
+        let transfer_id = debit_proof.id();
+
+        // Register the transfer on the network.
+        let msg_contents = Cmd::Transfer(TransferCmd::RegisterTransfer(debit_proof.clone()));
+
+        let message = Self::create_cmd_message(msg_contents);
+        trace!(
+            "Debit proof received and to be sent in RegisterTransfer req: {:?}",
+            debit_proof
         );
 
+        self.send_cmd_with_retry(
+            &message,
+            REGISTER_TRANSFER_RETRIES,
+            REGISTER_TRANSFER_RETRY_BACKOFF,
+        )
+        .await?;
+
+        let mut actor = self.transfer_actor.lock().await;
+        // First register with local actor, then reply.
+        let register_event = actor
+            .register(debit_proof)?
+            .ok_or_else(|| CoreError::from("No transfer event to register locally"))?;
+
+        actor.apply(ActorEvent::TransferRegistrationSent(register_event))?;
+        self.notify_balance_change(actor.balance()).await;
+
+        let _ = self.pending_transfers.lock().await.remove(&transfer_id);
+
+        Ok(())
+    }
+
+    /// Get a transfer validated by the network, keeping its debit proof as pending registration.
+    pub(crate) async fn validate_transfer(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+    ) -> Result<DebitAgreementProof, CoreError> {
+        self.validate_transfer_or_cancel(to, amount, future::pending())
+            .await
+    }
+
+    // Get a transfer validated by the network as with `validate_transfer`, unless `cancel`
+    // completes first. No transfer is initiated by our actor if it's cancelled before being sent,
+    // otherwise it's kept in `cancelled_transfers` until its validation arrives.
+    async fn validate_transfer_or_cancel<C>(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+        cancel: C,
+    ) -> Result<DebitAgreementProof, CoreError>
+    where
+        C: Future<Output = ()>,
+    {
+        self.check_writes_allowed()?;
+
+        let mut cancel = Box::pin(cancel);
+        if (&mut cancel).now_or_never().is_some() {
+            info!("Transfer to {:?} cancelled before being sent", to);
+            return Err(CoreError::OperationAborted);
+        }
+
+        self.complete_cancelled_transfers().await?;
+
         let signed_transfer = self
             .transfer_actor
             .lock()
@@ -138,7 +991,7 @@ impl Client {
             .ok_or_else(|| CoreError::from("No transfer generated by the actor."))?
             .signed_transfer;
 
-        println!(
+        debug!(
             "Signed transfer for send money: {:?}",
             signed_transfer.transfer
         );
@@ -153,30 +1006,81 @@ impl Client {
                 signed_transfer: signed_transfer.clone(),
             }))?;
 
-        let debit_proof: DebitAgreementProof = self
-            .await_validation(&message, signed_transfer.id())
-            .await?;
-
-        // Register the transfer on the network.
-        let msg_contents = Cmd::Transfer(TransferCmd::RegisterTransfer(debit_proof.clone()));
+        let validated = {
+            let validation = self.await_validation(&message, signed_transfer.id(), amount);
+            match future::select(cancel, Box::pin(validation)).await {
+                Either::Left(((), _)) => None,
+                Either::Right((result, _)) => Some(result),
+            }
+        };
+        let debit_proof: DebitAgreementProof = match validated {
+            Some(result) => result?,
+            None => {
+                info!(
+                    "Transfer {:?} cancelled while awaiting its validation",
+                    signed_transfer.id()
+                );
+                let _ = self
+                    .cancelled_transfers
+                    .lock()
+                    .await
+                    .insert(signed_transfer.id(), (message, amount));
+                self.complete_cancelled_transfers_in_background();
+                return Err(CoreError::OperationAborted);
+            }
+        };
 
-        let message = Self::create_cmd_message(msg_contents);
-        trace!(
-            "Debit proof received and to be sent in RegisterTransfer req: {:?}",
-            debit_proof
-        );
+        let _ = self
+            .pending_transfers
+            .lock()
+            .await
+            .insert(signed_transfer.id(), debit_proof.clone());
 
-        let _ = self.connection_manager.send_cmd(&message).await?;
+        Ok(debit_proof)
+    }
 
-        let mut actor = self.transfer_actor.lock().await;
-        // First register with local actor, then reply.
-        let register_event = actor
-            .register(debit_proof)?
-            .ok_or_else(|| CoreError::from("No transfer event to register locally"))?;
+    // Await the validation of the transfers cancelled after being sent, and register them,
+    // before our actor sends its next debit. Those failing are kept for the next attempt.
+    // Must be called while holding `debit_lock`.
+    pub(crate) async fn complete_cancelled_transfers(&mut self) -> Result<(), CoreError> {
+        loop {
+            let next = self.cancelled_transfers.lock().await.keys().next().copied();
+            let id = match next {
+                Some(id) => id,
+                None => return Ok(()),
+            };
+            let (message, amount) = match self.cancelled_transfers.lock().await.get(&id) {
+                Some(cancelled) => cancelled.clone(),
+                None => continue,
+            };
+
+            info!(
+                "Completing transfer {:?}, cancelled while awaiting its validation",
+                id
+            );
+            let debit_proof = self.await_validation(&message, id, amount).await?;
+            let _ = self
+                .pending_transfers
+                .lock()
+                .await
+                .insert(id, debit_proof.clone());
+            let _ = self.cancelled_transfers.lock().await.remove(&id);
+
+            self.register_pending_transfer(debit_proof).await?;
+        }
+    }
 
-        actor.apply(ActorEvent::TransferRegistrationSent(register_event))?;
+    // Complete the cancelled transfers without waiting for the next debit of our actor.
+    fn complete_cancelled_transfers_in_background(&self) {
+        let mut client = self.clone();
+        let _ = tokio::spawn(async move {
+            let debit_lock = client.debit_lock.clone();
+            let _debit_guard = debit_lock.lock().await;
 
-        Ok(())
+            if let Err(error) = client.complete_cancelled_transfers().await {
+                warn!("Failed to complete a cancelled transfer: {}", error);
+            }
+        });
     }
 }
 
@@ -189,10 +1093,18 @@ impl Client {
 mod tests {
 
     use super::*;
+    use crate::config_handler::Config;
+    use crate::connection_manager::ConnectionManager;
     use crate::crypto::shared_box;
-    use crate::utils::{generate_random_vector, test_utils::calculate_new_balance};
-    use safe_nd::{Blob, Error as SndError, Money, PublicBlob};
+    use crate::errors::PaymentFailureKind;
+    use crate::utils::{
+        generate_random_vector,
+        test_utils::{calculate_new_balance, FlakyTransport},
+    };
+    use crdts::Dot;
+    use safe_nd::{Blob, Error as SndError, Message, Money, PublicBlob};
     use std::str::FromStr;
+    use std::sync::Arc;
 
     #[tokio::test]
     #[cfg(feature = "simulated-payouts")]
@@ -214,6 +1126,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_reports_its_debits() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+        assert!(client.get_debits(0).await.is_empty());
+
+        client.send_money(pk2, Money::from_str("1")?).await?;
+        client.send_money(pk2, Money::from_str("2")?).await?;
+
+        let debits = client.get_debits(0).await;
+        assert_eq!(debits.len(), 2);
+        assert_eq!(debits[0].amount, Money::from_str("1")?);
+        assert_eq!(debits[1].amount, Money::from_str("2")?);
+        assert!(debits.iter().all(|transfer| transfer.to == pk2));
+
+        let debits = client.get_debits(1).await;
+        assert_eq!(debits.len(), 1);
+        assert_eq!(debits[0].amount, Money::from_str("2")?);
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[cfg(feature = "simulated-payouts")]
     async fn transfer_actor_can_send_several_transfers_and_thats_reflected_locally(
@@ -246,6 +1183,437 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_send_money_with_memo() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        let memo = b"invoice #1".to_vec();
+        let id = client
+            .send_money_with_memo(pk2, Money::from_str("1")?, memo.clone())
+            .await?;
+
+        assert_eq!(client.get_transfer_memo(id).await, Some(memo.clone()));
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+
+        // the memo is listed along with the transfer in our history
+        let sent = client.get_sent_transfers(0).await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].transfer.id, id);
+        assert_eq!(sent[0].transfer.to, pk2);
+        assert_eq!(sent[0].memo, Some(memo));
+
+        // memos over the maximum size are rejected before sending anything
+        let res = client
+            .send_money_with_memo(
+                pk2,
+                Money::from_str("1")?,
+                vec![0; MAX_TRANSFER_MEMO_SIZE + 1],
+            )
+            .await;
+        match res {
+            Err(CoreError::Unexpected(_)) => (),
+            res => panic!("Unexpected result sending an oversized memo: {:?}", res),
+        };
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_send_a_batch_of_payments() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+        let _ = client
+            .trigger_simulated_farming_payout(Money::from_str("100")?)
+            .await?;
+
+        let recipients: Vec<PublicKey> = (0..3)
+            .map(|_| PublicKey::Bls(shared_box::gen_bls_keypair().1))
+            .collect();
+        let payments = recipients
+            .iter()
+            .map(|pk| Ok((*pk, Money::from_str("5")?)))
+            .collect::<Result<Vec<_>, CoreError>>()?;
+
+        let results = client.send_money_batch(payments).await?;
+        assert_eq!(results.len(), 3);
+        for res in results {
+            res?;
+        }
+
+        // initial 10 on creation from farming simulation, plus 100, minus 15
+        assert_eq!(client.get_local_balance().await, Money::from_str("95")?);
+        for pk in recipients {
+            assert_eq!(client.get_balance_for(pk).await?, Money::from_str("5")?);
+        }
+
+        // a batch exceeding our balance fails before sending anything
+        let payments = vec![
+            (
+                PublicKey::Bls(shared_box::gen_bls_keypair().1),
+                Money::from_str("50")?,
+            ),
+            (
+                PublicKey::Bls(shared_box::gen_bls_keypair().1),
+                Money::from_str("50")?,
+            ),
+        ];
+        match client.send_money_batch(payments).await {
+            Err(CoreError::DataError(SndError::InsufficientBalance)) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        };
+        assert_eq!(client.get_local_balance().await, Money::from_str("95")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_does_not_send_money_twice_for_same_idempotency_key(
+    ) -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        let key = b"payment-1".to_vec();
+        let id = client
+            .send_money_with_idempotency_key(pk2, Money::from_str("1")?, Some(key.clone()))
+            .await?;
+        let retry_id = client
+            .send_money_with_idempotency_key(pk2, Money::from_str("1")?, Some(key))
+            .await?;
+
+        assert_eq!(id, retry_id);
+        // initial 10 on creation from farming simulation minus a single 1
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+        assert_eq!(client.get_balance().await?, Money::from_str("9")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_notifies_balance_changes() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+        let mut balance_changes = Box::pin(client.subscribe_balance_changes().await);
+
+        client.send_money(pk2, Money::from_str("1")?).await?;
+
+        // initial 10 on creation from farming simulation minus 1
+        let expected_balance = Money::from_str("9")?;
+        loop {
+            match balance_changes.next().await {
+                Some(balance) if balance == expected_balance => break,
+                Some(_) => continue,
+                None => panic!("Balance changes stream ended unexpectedly"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_notifies_incoming_transfers() -> Result<(), CoreError> {
+        let mut sender = Client::new(None).await?;
+        let mut recipient = Client::new(None).await?;
+        let mut incoming = Box::pin(recipient.incoming_transfers().await);
+
+        let sender_pk = sender.public_key().await;
+        sender
+            .send_money(recipient.public_key().await, Money::from_str("2")?)
+            .await?;
+        recipient.get_history().await?;
+
+        // the initial farming payout may come first
+        loop {
+            match incoming.next().await {
+                Some(transfer) if transfer.sender == sender_pk => {
+                    assert_eq!(transfer.amount, Money::from_str("2")?);
+                    break;
+                }
+                Some(_) => continue,
+                None => panic!("Incoming transfers stream ended unexpectedly"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_awaits_credit_from_a_key() -> Result<(), CoreError> {
+        let mut payer = Client::new(None).await?;
+        let mut payee = Client::new(None).await?;
+        let payer_pk = payer.public_key().await;
+
+        payer
+            .send_money(payee.public_key().await, Money::from_str("2")?)
+            .await?;
+
+        let amount = payee
+            .await_credit_from(payer_pk, Money::from_str("2")?, Duration::from_secs(10))
+            .await?;
+        assert_eq!(amount, Money::from_str("2")?);
+
+        // Nothing more comes from that key
+        match payee
+            .await_credit_from(payer_pk, Money::from_str("1")?, Duration::from_millis(500))
+            .await
+        {
+            Err(CoreError::RequestTimeout) => (),
+            res => panic!("Unexpected result awaiting a credit: {:?}", res),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_reports_transfer_status() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+        let id = client
+            .send_money_with_idempotency_key(pk2, Money::from_str("1")?, None)
+            .await?;
+
+        assert_eq!(
+            client.get_transfer_status(id).await?,
+            TransferStatus::Registered
+        );
+        assert_eq!(
+            client.get_transfer_status(Dot::new(pk2, 1)).await?,
+            TransferStatus::Unknown
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_retrieve_history_since_a_version() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        let full_history = client.get_history_since(0).await?;
+        // initial 10 on creation from farming simulation
+        assert!(!full_history.is_empty());
+
+        let _ = client.send_money(pk2, Money::from_str("1")?).await?;
+
+        let new_events = client.get_history_since(full_history.len()).await?;
+        assert!(!new_events.is_empty());
+
+        let updated_history = client.get_history_since(0).await?;
+        assert_eq!(updated_history.len(), full_history.len() + new_events.len());
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_register_a_pending_transfer() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        // Get the transfer validated, but stop before registering it
+        let debit_proof = client.validate_transfer(pk2, Money::from_str("1")?).await?;
+
+        let pending = client.pending_transfers().await;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id(), debit_proof.id());
+
+        client.register_pending_transfer(debit_proof).await?;
+
+        assert!(client.pending_transfers().await.is_empty());
+        // initial 10 on creation from farming simulation minus 1
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+        assert_eq!(client.get_balance().await?, Money::from_str("9")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_retries_registering_a_transfer() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+        let debit_proof = client.validate_transfer(pk2, Money::from_str("1")?).await?;
+
+        // The connection is lost while sending the registration, twice
+        let transport = Arc::new(FlakyTransport::new(
+            client.connection_manager.clone(),
+            2,
+            |msg| match msg {
+                Message::Cmd {
+                    cmd: Cmd::Transfer(TransferCmd::RegisterTransfer(_)),
+                    ..
+                } => true,
+                _ => false,
+            },
+        ));
+        client.connection_manager = ConnectionManager::with_transport(
+            Config::new().quic_p2p,
+            client.full_id.clone(),
+            transport.clone(),
+        )?;
+
+        client.register_pending_transfer(debit_proof).await?;
+
+        assert_eq!(transport.remaining_failures(), 0);
+        assert!(client.pending_transfers().await.is_empty());
+        // initial 10 on creation from farming simulation minus 1
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+        assert_eq!(client.get_balance().await?, Money::from_str("9")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_reports_pending_debits_in_balance_breakdown() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        // Get the transfer validated, but stop before registering it
+        let debit_proof = client.validate_transfer(pk2, Money::from_str("2")?).await?;
+
+        // initial 10 on creation from farming simulation, 2 of which are in flight
+        let breakdown = client.get_balance_breakdown().await;
+        assert_eq!(breakdown.settled, Money::from_str("10")?);
+        assert_eq!(breakdown.pending_out, Money::from_str("2")?);
+        assert_eq!(breakdown.available, Money::from_str("8")?);
+
+        client.register_pending_transfer(debit_proof).await?;
+
+        let breakdown = client.get_balance_breakdown().await;
+        assert_eq!(breakdown.settled, Money::from_str("8")?);
+        assert_eq!(breakdown.pending_out, Money::from_str("0")?);
+        assert_eq!(breakdown.available, Money::from_str("8")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_send_all_its_money() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        // initial 10 on creation from farming simulation
+        assert_eq!(client.send_all(pk2).await?, Money::from_str("10")?);
+        assert_eq!(client.get_local_balance().await, Money::from_str("0")?);
+        assert_eq!(client.get_balance().await?, Money::from_str("0")?);
+        assert_eq!(client.get_balance_for(pk2).await?, Money::from_str("10")?);
+
+        // Nothing is left to send
+        assert_eq!(client.send_all(pk2).await?, Money::from_str("0")?);
+        assert_eq!(client.get_balance_for(pk2).await?, Money::from_str("10")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_reverts_a_cancelled_transfer() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+        let balance = client.get_local_balance().await;
+
+        match client
+            .send_money_cancellable(pk2, Money::from_str("1")?, future::ready(()))
+            .await
+        {
+            Err(CoreError::OperationAborted) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        assert_eq!(client.get_local_balance().await, balance);
+        assert_eq!(
+            client.get_balance_breakdown().await.pending_out,
+            Money::from_str("0")?
+        );
+
+        // The actor can still send its next debit
+        client.send_money(pk2, Money::from_str("1")?).await?;
+        // initial 10 on creation from farming simulation minus 1
+        assert_eq!(client.get_local_balance().await, Money::from_str("9")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_cancels_a_pending_send() -> Result<(), CoreError> {
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+
+        let pk2 = PublicKey::Bls(pk2);
+
+        let mut client = Client::new(None).await?;
+
+        // Cancelled as soon as the transfer was sent for validation
+        let awaiting_validation = client.transfers_awaiting_validation.clone();
+        let cancel = async move {
+            while awaiting_validation.lock().await.is_empty() {
+                time::delay_for(Duration::from_millis(1)).await;
+            }
+        };
+        match client
+            .send_money_cancellable(pk2, Money::from_str("1")?, cancel)
+            .await
+        {
+            Err(CoreError::OperationAborted) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        // The Elders validate it nonetheless, so it's registered before our next transfer
+        client.send_money(pk2, Money::from_str("1")?).await?;
+
+        // initial 10 on creation from farming simulation minus 1, twice
+        assert_eq!(client.get_local_balance().await, Money::from_str("8")?);
+        assert_eq!(client.get_balance().await?, Money::from_str("8")?);
+        assert_eq!(client.get_balance_for(pk2).await?, Money::from_str("2")?);
+        assert_eq!(
+            client.get_balance_breakdown().await.pending_out,
+            Money::from_str("0")?
+        );
+
+        Ok(())
+    }
+
     // TODO: do we want to be able to send 0 transfer reqs? This should probably be an actor side check if not
     #[tokio::test]
     #[cfg(feature = "simulated-payouts")]
@@ -330,7 +1698,9 @@ mod tests {
         let data = Blob::Public(PublicBlob::new(generate_random_vector::<u8>(10)));
         let res = client.store_blob(data).await;
         match res {
-            Err(CoreError::DataError(SndError::InsufficientBalance)) => (),
+            Err(CoreError::PaymentFailed {
+                kind: PaymentFailureKind::InsufficientBalance,
+            }) => (),
             res => panic!(
                 "Unexpected result in money transfer test, putting without balance: {:?}",
                 res
@@ -1,10 +1,21 @@
+use futures::{
+    future::Future,
+    lock::Mutex,
+    stream::{self, StreamExt},
+};
 use log::{debug, info, trace, warn};
 use safe_nd::{
-    ClientFullId, Cmd, DebitAgreementProof, Message, Money, PublicKey, Query, QueryResponse,
-    TransferCmd, TransferId, TransferQuery,
+    ClientFullId, Cmd, DebitAgreementProof, Error as SndError, Message, Money, PublicKey, Query,
+    QueryResponse, ReplicaEvent, Transfer, TransferCmd, TransferId, TransferQuery,
 };
 use safe_transfers::{ActorEvent, ReplicaValidator, TransferInitiated};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
 use threshold_crypto::PublicKeySet;
+use tokio::time::{delay_for, timeout};
 
 /// Module for Money balance management
 pub mod balance_management;
@@ -18,7 +29,70 @@ pub use safe_transfers::TransferActor as SafeTransferActor;
 
 use crate::client::ConnectionManager;
 use crate::client::{Client, COST_OF_PUT};
-use crate::errors::CoreError;
+use crate::errors::{CoreError, PaymentFailureKind};
+
+/// Maximum number of balance queries in flight at once when getting the balances of several keys.
+const MAX_CONCURRENT_BALANCE_QUERIES: usize = 8;
+
+/// Interval at which the debit proofs received are checked for the transfer awaiting validation.
+const DEBIT_PROOF_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Configuration of the wait for a transfer to be validated by the network.
+///
+/// The default waits indefinitely, without ever re-sending the validation request.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TransferValidationConfig {
+    /// How long to wait for the validation before re-sending the request, or giving up.
+    /// No timeout if `None`.
+    pub timeout: Option<Duration>,
+    /// How many times the validation request is re-sent after a timeout.
+    pub max_retries: usize,
+}
+
+/// A transfer of Money received by this client, as notified by `Client::incoming_transfers`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncomingTransfer {
+    /// The id of the transfer, which identifies its sender.
+    pub id: TransferId,
+    /// The key the Money was sent from.
+    pub sender: PublicKey,
+    /// The amount credited to this client.
+    pub amount: Money,
+}
+
+/// A transfer of Money sent by this client, as listed by `Client::get_sent_transfers`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SentTransfer {
+    /// The transfer debited from our balance.
+    pub transfer: Transfer,
+    /// The memo attached to it with `Client::send_money_with_memo`, if any.
+    pub memo: Option<Vec<u8>>,
+}
+
+/// Status of a transfer, as known by `Client::get_transfer_status`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransferStatus {
+    /// Sent for validation by this client, which is still waiting for its debit proof.
+    Pending,
+    /// Validated by the network, but not registered yet.
+    Validated,
+    /// Registered on the network, and part of the transfer history of this client.
+    Registered,
+    /// Neither sent by this client, nor found in its transfer history.
+    Unknown,
+}
+
+/// Balance of a client split by what is still in flight, as returned by
+/// `Client::get_balance_breakdown`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BalanceBreakdown {
+    /// The balance of the local actor, from the transfers registered so far.
+    pub settled: Money,
+    /// The Money sent in transfers awaiting validation or registration.
+    pub pending_out: Money,
+    /// What is left to spend once the pending transfers are registered.
+    pub available: Money,
+}
 
 /// Simple client side validations
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -65,8 +139,65 @@ impl Client {
         self.get_balance_from_network(None).await
     }
 
+    /// Get the client's current coin balance, as with `get_balance`, but served from a cache
+    /// for a short while after being fetched from the network.
+    ///
+    /// How long the balance is cached is set by `ClientConfig::balance_cache_ttl`. Any change
+    /// to the local balance, e.g. after a write or a transfer, invalidates the cache, as does
+    /// `invalidate_balance_cache`.
+    ///
+    /// # Examples
+    ///
+    /// Check the balance repeatedly
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let balance = client.get_balance_cached().await?;
+    /// // Served from the cache
+    /// assert_eq!(client.get_balance_cached().await?, balance);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_balance_cached(&mut self) -> Result<Money, CoreError> {
+        let now = self.clock.now();
+        if let Some((balance, fetched_at)) = *self.balance_cache.lock().await {
+            if now.duration_since(fetched_at) < self.balance_cache_ttl {
+                trace!("Balance found in cache");
+                return Ok(balance);
+            }
+        }
+
+        let balance = self.get_balance_from_network(None).await?;
+        if self.balance_cache_ttl > Duration::from_secs(0) {
+            *self.balance_cache.lock().await = Some((balance, self.clock.now()));
+        }
+
+        Ok(balance)
+    }
+
+    /// Drop the balance cached by `get_balance_cached`, so it's fetched from the network next time.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let client = Client::new(None).await?;
+    /// client.invalidate_balance_cache().await;
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn invalidate_balance_cache(&self) {
+        *self.balance_cache.lock().await = None;
+    }
+
     /// Get balance for a Public Key on the network.
     ///
+    /// A key which never received any Money has no balance on the network, which is reported
+    /// as a balance of zero rather than an error. Any error returned is thus a failure to get
+    /// the balance, e.g. `CoreError::RequestTimeout` or `CoreError::ConnectionLost`, not a
+    /// sign that the key is unknown.
+    ///
     /// # Examples
     ///
     /// Retrieve an existing balance
@@ -93,7 +224,53 @@ impl Client {
         Self: Sized,
     {
         trace!("Get balance for {:?}", public_key);
-        self.get_balance_from_network(Some(public_key)).await
+        balance_or_zero(self.get_balance_from_network(Some(public_key)).await)
+    }
+
+    /// Get the balances of several Public Keys on the network.
+    ///
+    /// The balances are queried concurrently, with a bounded number of queries in flight.
+    /// Each key maps to the result of its own query, so failing to get one balance
+    /// doesn't fail the others. As with `get_balance_for`, a key without a balance has zero.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve several balances
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::PublicKey;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let keys: Vec<PublicKey> = (0..3)
+    ///     .map(|_| PublicKey::from(threshold_crypto::SecretKey::random().public_key()))
+    ///     .collect();
+    ///
+    /// let mut client = Client::new(None).await?;
+    /// let balances = client.get_balances_for(keys).await?;
+    /// for (key, balance) in balances {
+    ///     println!("{:?}: {:?}", key, balance);
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_balances_for(
+        &mut self,
+        keys: Vec<PublicKey>,
+    ) -> Result<BTreeMap<PublicKey, Result<Money, CoreError>>, CoreError> {
+        trace!("Get balances for {} keys", keys.len());
+
+        let balances = stream::iter(keys)
+            .map(|public_key| {
+                let mut client = self.clone();
+                async move {
+                    let balance = client.get_balance_for(public_key).await;
+                    (public_key, balance)
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_BALANCE_QUERIES)
+            .collect()
+            .await;
+
+        Ok(balances)
     }
 
     /// Retrieve the history of the account from the network and apply to our local client's AT2 actor.
@@ -115,32 +292,87 @@ impl Client {
     /// # Ok(()) } ); }
     /// ```
     pub async fn get_history(&mut self) -> Result<(), CoreError> {
+        let _ = self.get_history_since(0).await?;
+        Ok(())
+    }
+
+    /// Retrieve the history of the account from the network, starting at the given version, and apply
+    /// it to our local client's AT2 actor. The retrieved events are returned.
+    ///
+    /// The version of the history following the last event retrieved is kept by the client, and is
+    /// the one used by `send_money` to fetch only the history it doesn't know about yet.
+    ///
+    /// # Examples
+    ///
+    /// Retrieving the latest events of an existing balance history
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let secret_key = threshold_crypto::SecretKey::random();
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    ///
+    /// let all_events = client.get_history_since(0).await?;
+    /// // Nothing new has happened since
+    /// let new_events = client.get_history_since(all_events.len()).await?;
+    /// assert!(new_events.is_empty());
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_history_since(
+        &mut self,
+        from_version: usize,
+    ) -> Result<Vec<ReplicaEvent>, CoreError> {
         let public_key = *self.full_id.public_key();
-        info!("Getting SafeTransfers history for pk: {:?}", public_key);
+        info!(
+            "Getting SafeTransfers history for pk: {:?}, since version: {}",
+            public_key, from_version
+        );
 
         let msg_contents = Query::Transfer(TransferQuery::GetHistory {
             at: public_key,
-            since_version: 0,
+            since_version: from_version,
         });
 
-        let message = Self::create_query_message(msg_contents);
-
         // This is a normal response manager request. We want quorum on this for now...
-        let res = self.connection_manager.send_query(&message).await?;
+        let res = self.send_query(msg_contents).await?;
 
         let history = match res {
             QueryResponse::GetHistory(history) => history.map_err(CoreError::from),
-            _ => Err(CoreError::from(format!(
-                "Unexpected response when retrieving account history {:?}",
-                res
-            ))),
+            other => Err(CoreError::unexpected_query_response("GetHistory", &other)),
         }?;
 
+        let mut new_events = Vec::new();
+        {
+            // Move our cursor past the events we just retrieved
+            let mut version = self.transfer_history_version.lock().await;
+            *version = std::cmp::max(*version, from_version + history.len());
+
+            // And keep the ones we didn't know yet
+            let mut events = self.transfer_history.lock().await;
+            if from_version <= events.len() {
+                let known = events.len() - from_version;
+                new_events.extend(history.iter().skip(known).cloned());
+                events.extend(new_events.iter().cloned());
+            }
+        }
+
+        self.sync_actor_with_history(history.clone()).await?;
+        self.notify_incoming_transfers(&new_events);
+
+        Ok(history)
+    }
+
+    // Apply the events of a transfer history to our local actor.
+    pub(crate) async fn sync_actor_with_history(
+        &self,
+        history: Vec<ReplicaEvent>,
+    ) -> Result<(), CoreError> {
         let mut actor = self.transfer_actor.lock().await;
         match actor.synch(history) {
             Ok(synced_transfer_outcome) => {
                 if let Some(transfers) = synced_transfer_outcome {
                     actor.apply(ActorEvent::TransfersSynched(transfers))?;
+                    self.notify_balance_change(actor.balance()).await;
                 }
             }
             Err(error) => {
@@ -153,7 +385,7 @@ impl Client {
 
                 warn!(
                     "No new transfer history  by TransferActor for pk: {:?}",
-                    public_key
+                    self.public_key
                 );
 
                 warn!("current balance {:?}", actor.balance());
@@ -163,12 +395,150 @@ impl Client {
         Ok(())
     }
 
-    /// Validates a tranction for paying store_cost
+    /// Reconcile the local actor with the network, e.g. after a period offline.
+    ///
+    /// The whole transfer history is retrieved and replayed into a fresh actor, which replaces
+    /// the local one, and the reconciled balance is returned. Should the local balance have
+    /// diverged from the network's, the divergence is logged.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let secret_key = threshold_crypto::SecretKey::random();
+    /// let mut client = Client::new(Some(secret_key)).await?;
+    ///
+    /// let balance = client.resync_balance().await?;
+    /// assert_eq!(client.get_local_balance().await, balance);
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn resync_balance(&mut self) -> Result<Money, CoreError> {
+        info!("Resyncing transfer actor for pk: {:?}", self.public_key);
+
+        // No transfer can be debited from the actor while it's being replaced
+        let debit_lock = self.debit_lock.clone();
+        let _debit_guard = debit_lock.lock().await;
+
+        let local_balance = self.get_local_balance().await;
+        let network_balance = self.get_balance_from_network(None).await?;
+
+        let msg_contents = Query::Transfer(TransferQuery::GetHistory {
+            at: self.public_key,
+            since_version: 0,
+        });
+        let history = match self.send_query(msg_contents).await? {
+            QueryResponse::GetHistory(history) => history.map_err(CoreError::from),
+            other => Err(CoreError::unexpected_query_response("GetHistory", &other)),
+        }?;
+
+        *self.transfer_history_version.lock().await = history.len();
+        *self.transfer_history.lock().await = history.clone();
+        self.rebuild_actor_from(history).await?;
+        self.invalidate_balance_cache().await;
+
+        let balance = self.get_local_balance().await;
+        if local_balance != network_balance {
+            warn!(
+                "Local balance {:?} had diverged from the network's {:?}, resynced to {:?}",
+                local_balance, network_balance, balance
+            );
+        }
+
+        Ok(balance)
+    }
+
+    // Replace our actor by a fresh one, synced with the given transfer history.
+    async fn rebuild_actor_from(&self, history: Vec<ReplicaEvent>) -> Result<(), CoreError> {
+        *self.transfer_actor.lock().await = SafeTransferActor::new(
+            self.full_id.keypair().clone(),
+            self.replicas_pk_set.clone(),
+            ClientTransferValidator {},
+        );
+
+        self.sync_actor_with_history(history).await
+    }
+
+    /// Get the price paid for a single write (PUT) operation, without making any payment.
+    ///
+    /// Every write (storing, appending to or deleting data, editing permissions or owners)
+    /// is paid this amount.
+    ///
+    /// # Examples
+    ///
+    /// Check the cost of a write before committing to it
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// let price = client.get_write_price().await?;
+    /// if price > client.get_local_balance().await {
+    ///     println!("Not enough money to pay {} for this write", price);
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn get_write_price(&mut self) -> Result<Money, CoreError> {
+        // TODO: query the store cost from the network once it can be queried for.
+        // Until then it's the fixed price our write payments are made with.
+        Ok(COST_OF_PUT)
+    }
+
+    /// Check whether the local balance is enough to pay for the given number of writes.
+    ///
+    /// The result is advisory: it's checked against the local balance, without querying the
+    /// network, so it can be outdated, and the price may change before the writes are paid.
+    ///
+    /// # Examples
+    ///
+    /// Check a batch of writes can be paid before starting it
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let mut client = Client::new(None).await?;
+    /// if !client.can_afford_writes(100).await? {
+    ///     println!("Not enough money to pay for 100 writes");
+    /// }
+    /// # Ok(()) } ); }
+    /// ```
+    pub async fn can_afford_writes(&mut self, count: u64) -> Result<bool, CoreError> {
+        let price = self.get_write_price().await?;
+        let balance = self.get_local_balance().await;
+
+        // A total which can't even be represented is never affordable
+        match price.as_nano().checked_mul(count) {
+            Some(total) => Ok(Money::from_nano(total) <= balance),
+            None => Ok(false),
+        }
+    }
+
+    /// Validates a tranction for paying store_cost, and registers it with the local actor
+    ///
+    /// Failures are reported as `CoreError::PaymentFailed`, but for read-only clients.
     pub(crate) async fn create_write_payment_proof(
         &mut self,
     ) -> Result<DebitAgreementProof, CoreError> {
         info!("Sending requests for payment for write operation");
+        self.check_writes_allowed()?;
+
+        // Writes debit the same actor as transfers, so they have to wait for any transfer
+        // in flight on this client or its clones, until their own debit is registered
+        let debit_lock = self.debit_lock.clone();
+        let _debit_guard = debit_lock.lock().await;
+
+        let payment_proof = self
+            .try_create_write_payment_proof()
+            .await
+            .map_err(payment_failed)?;
+        self.apply_write_payment_to_local_actor(payment_proof.clone())
+            .await?;
 
+        Ok(payment_proof)
+    }
+
+    async fn try_create_write_payment_proof(&mut self) -> Result<DebitAgreementProof, CoreError> {
         //set up message
         let _full_id = self.full_id.clone();
 
@@ -176,6 +546,8 @@ impl Client {
 
         let section_key = PublicKey::Bls(self.replicas_pk_set.public_key());
 
+        self.complete_cancelled_transfers().await?;
+
         let signed_transfer = self
             .transfer_actor
             .lock()
@@ -198,10 +570,14 @@ impl Client {
             }))?;
 
         let payment_proof: DebitAgreementProof = self
-            .await_validation(&transfer_message, signed_transfer.id())
-            .await?;
+            .await_validation(&transfer_message, signed_transfer.id(), COST_OF_PUT)
+            .await
+            .map_err(validation_failed)?;
 
         debug!("Payment proof retrieved");
+        #[cfg(feature = "metrics")]
+        self.metrics.record_payment_proof_created();
+
         Ok(payment_proof)
     }
 
@@ -220,24 +596,127 @@ impl Client {
 
         match res {
             QueryResponse::GetReplicaKeys(pk_set) => Ok(pk_set?),
-            _ => Err(CoreError::from(format!(
-                "Unexpected response when retrieving account replica keys for {:?}",
-                full_id.public_key()
-            ))),
+            other => Err(CoreError::unexpected_query_response(
+                "GetReplicaKeys",
+                &other,
+            )),
         }
     }
 
     /// Send message and await validation and constructing of DebitAgreementProof
     async fn await_validation(
         &mut self,
-        _message: &Message,
-        _id: TransferId,
+        message: &Message,
+        id: TransferId,
+        amount: Money,
     ) -> Result<DebitAgreementProof, CoreError> {
         info!("Awaiting transfer validation");
-        // self.connection_manager.send_cmd(&message).await?;
-        // let proof = self.check_debit_cache(id).await;
-        // Ok(proof)
-        unimplemented!()
+        let connection_manager = self.connection_manager.clone();
+        let debit_cache = self.debit_cache.clone();
+
+        let _ = self
+            .transfers_awaiting_validation
+            .lock()
+            .await
+            .insert(id, amount);
+        let result = send_until_validated(
+            self.validation_config,
+            || {
+                let mut connection_manager = connection_manager.clone();
+                let message = message.clone();
+                async move { connection_manager.send_cmd(&message).await }
+            },
+            || wait_for_debit_proof(debit_cache.clone(), id),
+        )
+        .await;
+        let _ = self.transfers_awaiting_validation.lock().await.remove(&id);
+
+        result
+    }
+}
+
+// Report a key the network has no balance for as having a zero balance.
+fn balance_or_zero(result: Result<Money, CoreError>) -> Result<Money, CoreError> {
+    match result {
+        Err(CoreError::DataError(SndError::NoSuchBalance)) => Ok(Money::from_nano(0)),
+        result => result,
+    }
+}
+
+// Tell why paying for a write failed with the given error.
+fn payment_failed(error: CoreError) -> CoreError {
+    let kind = match error {
+        CoreError::DataError(SndError::InsufficientBalance) => {
+            PaymentFailureKind::InsufficientBalance
+        }
+        CoreError::RequestTimeout | CoreError::ConnectionLost | CoreError::QuicP2p(_) => {
+            PaymentFailureKind::Network
+        }
+        CoreError::PaymentFailed { kind } => kind,
+        error => PaymentFailureKind::Other(error.to_string()),
+    };
+    warn!("Payment for write failed: {}", kind);
+
+    CoreError::PaymentFailed { kind }
+}
+
+// Tell a payment whose validation timed out from one failing on any other timeout,
+// such as getting our transfer history.
+fn validation_failed(error: CoreError) -> CoreError {
+    match error {
+        CoreError::RequestTimeout => CoreError::PaymentFailed {
+            kind: PaymentFailureKind::ValidationTimeout,
+        },
+        error => error,
+    }
+}
+
+// Send the validation request, and wait for its debit proof, re-sending it on timeout
+// as many times as the config allows.
+async fn send_until_validated<S, SFut, W, WFut>(
+    config: TransferValidationConfig,
+    mut send: S,
+    mut wait: W,
+) -> Result<DebitAgreementProof, CoreError>
+where
+    S: FnMut() -> SFut,
+    SFut: Future<Output = Result<(), CoreError>>,
+    W: FnMut() -> WFut,
+    WFut: Future<Output = DebitAgreementProof>,
+{
+    let mut retries = 0;
+    loop {
+        send().await?;
+
+        let duration = match config.timeout {
+            Some(duration) => duration,
+            None => return Ok(wait().await),
+        };
+
+        match timeout(duration, wait()).await {
+            Ok(proof) => return Ok(proof),
+            Err(_) if retries < config.max_retries => {
+                retries += 1;
+                warn!(
+                    "Transfer validation timed out, re-sending it (retry {} of {})",
+                    retries, config.max_retries
+                );
+            }
+            Err(_) => return Err(CoreError::RequestTimeout),
+        }
+    }
+}
+
+// Wait until the debit proof of the given transfer is received from the network.
+async fn wait_for_debit_proof(
+    debit_cache: Arc<Mutex<HashMap<TransferId, DebitAgreementProof>>>,
+    id: TransferId,
+) -> DebitAgreementProof {
+    loop {
+        if let Some(proof) = debit_cache.lock().await.remove(&id) {
+            return proof;
+        }
+        delay_for(DEBIT_PROOF_POLL_INTERVAL).await;
     }
 }
 
@@ -251,8 +730,184 @@ mod tests {
 
     use super::*;
     use crate::crypto::shared_box;
+    use crate::utils::test_utils::calculate_new_balance;
     use safe_nd::Money;
     use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use xor_name::XorName;
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_caches_balance_until_invalidated() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let balance = client.get_balance_cached().await?;
+        let queries_sent = client.connection_manager.queries_sent();
+        assert_eq!(client.get_balance_cached().await?, balance);
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent);
+
+        // Sending money changes our balance, so it's fetched again
+        let pk = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        client.send_money(pk, Money::from_str("1")?).await?;
+        assert!(client.balance_cache.lock().await.is_none());
+
+        let queries_sent = client.connection_manager.queries_sent();
+        assert_eq!(client.get_balance_cached().await?, Money::from_str("9")?);
+        assert_eq!(client.connection_manager.queries_sent(), queries_sent + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_debits_writes_and_transfers_one_at_a_time() -> Result<(), CoreError> {
+        let mut writer = Client::new(None).await?;
+        let mut sender = writer.clone();
+        let pk = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+
+        let writes = async {
+            for _ in 0..3 {
+                let _ = writer
+                    .store_private_sequence_owner_only(None, XorName::random(), 15000)
+                    .await?;
+            }
+            Ok::<_, CoreError>(())
+        };
+        let transfers = async {
+            for _ in 0..3 {
+                sender.send_money(pk, Money::from_str("1")?).await?;
+            }
+            Ok::<_, CoreError>(())
+        };
+        let (written, transferred) = futures::join!(writes, transfers);
+        written?;
+        transferred?;
+
+        let expected =
+            calculate_new_balance(Money::from_str("10")?, Some(3), Some(Money::from_str("3")?));
+        assert_eq!(writer.get_local_balance().await, expected);
+        assert_eq!(writer.get_balance_from_network(None).await?, expected);
+        assert_eq!(
+            writer.get_balance_from_network(Some(pk)).await?,
+            Money::from_str("3")?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_check_affordable_writes() -> Result<(), CoreError> {
+        let (sk, _pk) = shared_box::gen_bls_keypair();
+        let mut client = Client::new(Some(sk)).await?;
+        let price = client.get_write_price().await?;
+        let _ = client
+            .trigger_simulated_farming_payout(Money::from_nano(3 * price.as_nano()))
+            .await?;
+
+        assert!(client.can_afford_writes(0).await?);
+        assert!(client.can_afford_writes(1).await?);
+        assert!(client.can_afford_writes(3).await?);
+        assert!(!client.can_afford_writes(4).await?);
+        assert!(!client.can_afford_writes(u64::MAX).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_get_balances_for_several_keys() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let funded_1 = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        let funded_2 = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        let unfunded = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        client.send_money(funded_1, Money::from_str("1")?).await?;
+        client.send_money(funded_2, Money::from_str("2")?).await?;
+
+        let balances = client
+            .get_balances_for(vec![funded_1, funded_2, unfunded])
+            .await?;
+        assert_eq!(balances.len(), 3);
+
+        match &balances[&funded_1] {
+            Ok(balance) => assert_eq!(*balance, Money::from_str("1")?),
+            Err(error) => panic!("Unexpected error getting balance: {:?}", error),
+        }
+        match &balances[&funded_2] {
+            Ok(balance) => assert_eq!(*balance, Money::from_str("2")?),
+            Err(error) => panic!("Unexpected error getting balance: {:?}", error),
+        }
+        // A key without a balance has zero, as with `get_balance_for`
+        match &balances[&unfunded] {
+            Ok(balance) => assert_eq!(*balance, Money::from_str("0")?),
+            Err(error) => panic!("Unexpected error getting balance: {:?}", error),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_gets_balance_for_another_key() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        let funded = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        client.send_money(funded, Money::from_str("3")?).await?;
+        assert_eq!(client.get_balance_for(funded).await?, Money::from_str("3")?);
+
+        // A key which never received any Money has a zero balance
+        let unfunded = PublicKey::Bls(shared_box::gen_bls_keypair().1);
+        assert_eq!(
+            client.get_balance_for(unfunded).await?,
+            Money::from_str("0")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn balance_for_a_key_distinguishes_network_errors_from_no_balance() -> Result<(), CoreError> {
+        assert_eq!(
+            balance_or_zero(Err(CoreError::from(SndError::NoSuchBalance)))?,
+            Money::from_str("0")?
+        );
+        assert_eq!(
+            balance_or_zero(Ok(Money::from_str("2")?))?,
+            Money::from_str("2")?
+        );
+        match balance_or_zero(Err(CoreError::ConnectionLost)) {
+            Err(CoreError::ConnectionLost) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_resyncs_balance_with_the_network() -> Result<(), CoreError> {
+        let mut client = Client::new(None).await?;
+
+        // Our actor misses a credit, and loses all its history
+        let mut payer = Client::new(None).await?;
+        payer
+            .send_money(client.public_key().await, Money::from_str("5")?)
+            .await?;
+        *client.transfer_actor.lock().await = SafeTransferActor::new(
+            client.full_id.keypair().clone(),
+            client.replicas_pk_set.clone(),
+            ClientTransferValidator {},
+        );
+        assert_eq!(client.get_local_balance().await, Money::from_str("0")?);
+
+        // initial 10 on creation from farming simulation plus 5 received
+        assert_eq!(client.resync_balance().await?, Money::from_str("15")?);
+        assert_eq!(client.get_local_balance().await, Money::from_str("15")?);
+        assert_eq!(client.get_balance().await?, Money::from_str("15")?);
+
+        Ok(())
+    }
 
     #[tokio::test]
     async fn transfer_actor_creation_hydration_for_nonexistant_balance() -> Result<(), CoreError> {
@@ -293,4 +948,95 @@ mod tests {
             Err(e) => panic!("Account should exist {:?}", e),
         }
     }
+
+    #[tokio::test]
+    async fn transfer_validation_times_out_after_retries() -> Result<(), CoreError> {
+        let config = TransferValidationConfig {
+            timeout: Some(Duration::from_millis(10)),
+            max_retries: 2,
+        };
+        let sends = AtomicUsize::new(0);
+
+        // The network never responds to our validation requests
+        let res = send_until_validated(
+            config,
+            || {
+                let _ = sends.fetch_add(1, Ordering::SeqCst);
+                futures::future::ready(Ok(()))
+            },
+            futures::future::pending,
+        )
+        .await;
+
+        match res {
+            Err(CoreError::RequestTimeout) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+        // First attempt plus the retries
+        assert_eq!(sends.load(Ordering::SeqCst), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn transfer_validation_waits_indefinitely_by_default() -> Result<(), CoreError> {
+        let config = TransferValidationConfig::default();
+        assert_eq!(config.timeout, None);
+        assert_eq!(config.max_retries, 0);
+
+        // The network never responds, and we keep waiting without re-sending the request
+        let sends = AtomicUsize::new(0);
+        let res = timeout(
+            Duration::from_millis(300),
+            send_until_validated(
+                config,
+                || {
+                    let _ = sends.fetch_add(1, Ordering::SeqCst);
+                    futures::future::ready(Ok(()))
+                },
+                futures::future::pending,
+            ),
+        )
+        .await;
+
+        // Still waiting when our own deadline elapses, after the first send only
+        assert!(res.is_err());
+        assert_eq!(sends.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_payment_fails_on_validation_timeout() -> Result<(), CoreError> {
+        let config = TransferValidationConfig {
+            timeout: Some(Duration::from_millis(10)),
+            max_retries: 0,
+        };
+
+        let res = send_until_validated(
+            config,
+            || futures::future::ready(Ok(())),
+            futures::future::pending,
+        )
+        .await
+        .map_err(validation_failed)
+        .map_err(payment_failed);
+
+        match res {
+            Err(CoreError::PaymentFailed {
+                kind: PaymentFailureKind::ValidationTimeout,
+            }) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        // Any other timeout of the payment is a network failure
+        match payment_failed(CoreError::RequestTimeout) {
+            CoreError::PaymentFailed {
+                kind: PaymentFailureKind::Network,
+            } => (),
+            error => panic!("Unexpected error: {:?}", error),
+        }
+
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use safe_nd::Money;
 
 #[cfg(feature = "simulated-payouts")]
-use safe_nd::{Cmd, Transfer, TransferCmd};
+use safe_nd::{Cmd, PublicKey, Transfer, TransferCmd};
 
 use crate::client::Client;
 use crate::errors::CoreError;
@@ -55,11 +55,45 @@ impl Client {
         amount: Money,
     ) -> Result<(), CoreError> {
         let pk = *self.full_id().await.public_key();
-        info!("Triggering a simulated farming payout to: {:?}", pk);
+        self.trigger_simulated_farming_payout_to(pk, amount).await
+    }
+
+    #[cfg(feature = "simulated-payouts")]
+    /// Simulate a farming payout & add a balance to any PublicKey.
+    ///
+    /// Useful for testing to seed the balances of other keys directly. If the PublicKey is the
+    /// client's own, its transfer actor is kept up to date as with `trigger_simulated_farming_payout`.
+    ///
+    /// # Examples
+    ///
+    /// Add 100 money to another key
+    ///
+    /// ```no_run
+    /// # extern crate tokio; use safe_core::CoreError;
+    /// use safe_core::Client;
+    /// use safe_nd::{Money, PublicKey};
+    /// use std::str::FromStr;
+    /// # #[tokio::main] async fn main() { let _: Result<(), CoreError> = futures::executor::block_on( async {
+    /// let pk = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+    ///
+    /// let mut client = Client::new(None).await?;
+    /// let target_balance = Money::from_str("100")?;
+    /// client.trigger_simulated_farming_payout_to(pk, target_balance).await?;
+    ///
+    /// let balance = client.get_balance_for(pk).await?;
+    /// assert_eq!(balance, target_balance);
+    /// # Ok(())} );}
+    /// ```
+    pub async fn trigger_simulated_farming_payout_to(
+        &mut self,
+        to: PublicKey,
+        amount: Money,
+    ) -> Result<(), CoreError> {
+        info!("Triggering a simulated farming payout to: {:?}", to);
         self.simulated_farming_payout_dot.apply_inc();
 
         let simulated_transfer = Transfer {
-            to: pk,
+            to,
             amount,
             id: self.simulated_farming_payout_dot,
         };
@@ -72,10 +106,12 @@ impl Client {
         let _ = self.connection_manager.send_cmd(&message).await?;
 
         // If we're getting the payout for our own actor, update it here
-        info!("Applying simulated payout locally, via query for history...");
+        if to == *self.full_id().await.public_key() {
+            info!("Applying simulated payout locally, via query for history...");
 
-        // get full history from network and apply locally
-        self.get_history().await?;
+            // get full history from network and apply locally
+            self.get_history().await?;
+        }
 
         Ok(())
     }
@@ -116,4 +152,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[cfg(feature = "simulated-payouts")]
+    async fn transfer_actor_can_trigger_simulated_farming_payout_to_another_key(
+    ) -> Result<(), CoreError> {
+        let (sk, _pk) = shared_box::gen_bls_keypair();
+        let (_sk2, pk2) = shared_box::gen_bls_keypair();
+        let pk2 = PublicKey::Bls(pk2);
+        let mut client = Client::new(Some(sk)).await?;
+
+        client
+            .trigger_simulated_farming_payout_to(pk2, Money::from_str("50")?)
+            .await?;
+
+        assert_eq!(client.get_balance_for(pk2).await?, Money::from_str("50")?);
+        // Our own balance is untouched
+        assert_eq!(client.get_local_balance().await, Money::from_str("0")?);
+
+        Ok(())
+    }
 }
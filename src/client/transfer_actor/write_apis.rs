@@ -6,8 +6,8 @@ use crate::errors::CoreError;
 
 /// Handle Write API msg_contents for a given Client.
 impl Client {
-    /// Apply a successfull payment locally after TransferRegistration has been sent to the network.
-    pub(crate) async fn apply_write_payment_to_local_actor(
+    /// Apply a successfull payment locally, as the write it pays for registers it with the network.
+    pub(super) async fn apply_write_payment_to_local_actor(
         &mut self,
         debit_proof: DebitAgreementProof,
     ) -> Result<(), CoreError> {
@@ -18,6 +18,7 @@ impl Client {
             .ok_or_else(|| CoreError::from("No events to register for proof."))?;
 
         actor.apply(ActorEvent::TransferRegistrationSent(register_event))?;
+        self.notify_balance_change(actor.balance()).await;
 
         Ok(())
     }
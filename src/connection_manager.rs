@@ -6,11 +6,14 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+#[cfg(feature = "metrics")]
+use crate::metrics::Metrics;
 use crate::CoreError;
+use async_trait::async_trait;
 use bincode::{deserialize, serialize};
 use bytes::Bytes;
 use futures::{
-    future::{join_all, select_all},
+    future::{join_all, select_all, BoxFuture, Future, FutureExt},
     lock::Mutex,
 };
 use log::{error, info, trace, warn};
@@ -20,11 +23,79 @@ use safe_nd::{
     Proof, QueryResponse,
 };
 use std::sync::mpsc::Sender;
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use std::{
+    cmp,
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::time::{delay_for, timeout};
 
 /// Simple map for correlating a response with votes from various elder responses.
 type VoteMap = HashMap<QueryResponse, usize>;
 
+/// Configuration of the reconnection attempts made when the connection to the network is lost.
+///
+/// The delay before each attempt starts at `base_delay` and doubles with every attempt, up to `max_delay`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReconnectConfig {
+    /// Delay before the first reconnection attempt.
+    pub base_delay: Duration,
+    /// Maximum delay between two reconnection attempts.
+    pub max_delay: Duration,
+    /// Number of reconnection attempts before failing with `CoreError::ConnectionLost`.
+    pub max_attempts: usize,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    // Delay to wait before the given (zero based) reconnection attempt.
+    fn delay(&self, attempt: usize) -> Duration {
+        let mut delay = cmp::min(self.base_delay, self.max_delay);
+        for _ in 0..attempt {
+            delay = cmp::min(delay * 2, self.max_delay);
+        }
+        delay
+    }
+}
+
+/// Health of the connection to the network, as reported by `ConnectionManager::status`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConnectionStatus {
+    /// Whether messages can currently be sent to the network.
+    pub connected: bool,
+    /// Number of Elders a connection is currently held with.
+    pub peer_count: usize,
+    /// When a message was last sent successfully, if ever.
+    pub last_success: Option<Instant>,
+}
+
+/// Delivers the messages of a `ConnectionManager` in place of the network.
+///
+/// A connection manager sends its messages to the Elders over QUIC, unless it was created with
+/// `ConnectionManager::with_transport`. Tests can thus observe the messages sent by a `Client`,
+/// and answer its queries, without a live network.
+#[async_trait]
+pub trait MessageTransport: Send + Sync {
+    /// Deliver a command, without awaiting any response.
+    async fn send_cmd(&self, msg: &Message) -> Result<(), CoreError>;
+    /// Deliver a query, awaiting its response.
+    async fn send_query(&self, msg: &Message) -> Result<QueryResponse, CoreError>;
+}
+
 /// Initialises `QuicP2p` instance which can bootstrap to the network, establish
 /// connections and send messages to several nodes, as well as await responses from them.
 #[derive(Clone)]
@@ -32,6 +103,19 @@ pub struct ConnectionManager {
     full_id: ClientFullId,
     quic_p2p: QuicP2p,
     elders: Vec<Arc<Mutex<Connection>>>,
+    reconnect_config: ReconnectConfig,
+    // How long a command or query may take, reconnections included
+    request_timeout: Option<Duration>,
+    // Shared with all the clones, which can't be used anymore once disconnected
+    disconnected: Arc<AtomicBool>,
+    // Number of queries sent through this connection manager and its clones
+    queries_sent: Arc<AtomicUsize>,
+    // When a command or query last succeeded, shared with all the clones
+    last_success: Arc<Mutex<Option<Instant>>>,
+    // Where messages are delivered instead of the Elders, if set
+    transport: Option<Arc<dyn MessageTransport>>,
+    #[cfg(feature = "metrics")]
+    metrics: Arc<Metrics>,
 }
 
 impl ConnectionManager {
@@ -44,9 +128,96 @@ impl ConnectionManager {
             full_id,
             quic_p2p,
             elders: Vec::default(),
+            reconnect_config: ReconnectConfig::default(),
+            request_timeout: None,
+            disconnected: Arc::new(AtomicBool::new(false)),
+            queries_sent: Arc::new(AtomicUsize::new(0)),
+            last_success: Arc::new(Mutex::new(None)),
+            transport: None,
+            #[cfg(feature = "metrics")]
+            metrics: Arc::new(Metrics::default()),
         })
     }
 
+    /// Create a connection manager delivering all its messages to the given transport rather
+    /// than to the network. It doesn't need to be bootstrapped, and never reconnects.
+    pub fn with_transport(
+        config: QuicP2pConfig,
+        full_id: ClientFullId,
+        transport: Arc<dyn MessageTransport>,
+    ) -> Result<Self, CoreError> {
+        let mut connection_manager = Self::new(config, full_id)?;
+        connection_manager.transport = Some(transport);
+        Ok(connection_manager)
+    }
+
+    /// Close the connections to the network. Any later message sent fails with
+    /// `CoreError::NotConnected`. Disconnecting more than once does nothing.
+    pub fn disconnect(&mut self) {
+        if self.disconnected.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        info!("Disconnecting from the network");
+        // Connections are closed once dropped
+        self.elders.clear();
+    }
+
+    /// Whether `disconnect` was called on this connection manager, or any of its clones.
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected.load(Ordering::SeqCst)
+    }
+
+    /// Current health of the connection to the network.
+    pub async fn status(&self) -> ConnectionStatus {
+        let connected = !self.is_disconnected() && !self.elders.is_empty();
+        ConnectionStatus {
+            connected,
+            peer_count: if connected { self.elders.len() } else { 0 },
+            last_success: *self.last_success.lock().await,
+        }
+    }
+
+    /// Number of queries sent to the network by this connection manager, or any of its clones.
+    #[cfg(any(test, feature = "simulated-payouts", feature = "testing"))]
+    pub fn queries_sent(&self) -> usize {
+        self.queries_sent.load(Ordering::SeqCst)
+    }
+
+    /// Metrics of the messages sent by this connection manager, and all its clones.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn metrics(&self) -> Arc<Metrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    /// Reconnect to the network with another identity, which all messages are then signed with.
+    ///
+    /// Should reconnecting fail, the previous identity is kept.
+    pub async fn switch_identity(&mut self, full_id: ClientFullId) -> Result<(), CoreError> {
+        if self.is_disconnected() {
+            return Err(CoreError::NotConnected);
+        }
+
+        info!("Switching identity to public_id: {:?}", full_id.public_id());
+        let previous = std::mem::replace(&mut self.full_id, full_id);
+        if let Err(error) = self.reconnect().await {
+            self.full_id = previous;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Set how reconnecting to the network is attempted when the connection is lost.
+    pub fn set_reconnect_config(&mut self, config: ReconnectConfig) {
+        self.reconnect_config = config;
+    }
+
+    /// Set how long a command or query may take, reconnections included, before failing with
+    /// `CoreError::RequestTimeout`. No timeout if `None`, which is the default.
+    pub fn set_request_timeout(&mut self, request_timeout: Option<Duration>) {
+        self.request_timeout = request_timeout;
+    }
+
     /// Bootstrap to the network maintaining connections to several nodes.
     pub async fn bootstrap(&mut self) -> Result<(), CoreError> {
         trace!(
@@ -63,7 +234,101 @@ impl ConnectionManager {
     }
 
     /// Send a `Message` to the network without awaiting for a response.
+    ///
+    /// Should the connection be lost, reconnecting is attempted before failing.
     pub async fn send_cmd(&mut self, msg: &Message) -> Result<(), CoreError> {
+        if self.is_disconnected() {
+            return Err(CoreError::NotConnected);
+        }
+
+        #[cfg(feature = "metrics")]
+        self.metrics.record_cmd_sent();
+
+        let request_timeout = self.request_timeout;
+        with_request_timeout(request_timeout, self.deliver_cmd(msg)).await?;
+
+        self.record_success().await;
+        Ok(())
+    }
+
+    // Deliver a command to the transport, or else to the Elders.
+    async fn deliver_cmd(&mut self, msg: &Message) -> Result<(), CoreError> {
+        if let Some(transport) = self.transport.clone() {
+            return transport.send_cmd(msg).await;
+        }
+
+        let config = self.reconnect_config;
+        with_reconnect(
+            self,
+            config,
+            |cm| {
+                let msg = msg.clone();
+                async move { cm.try_send_cmd(&msg).await }.boxed()
+            },
+            |cm| cm.reconnect().boxed(),
+        )
+        .await
+    }
+
+    /// Send a Query `Message` to the network awaiting for the response.
+    ///
+    /// Should the connection be lost, reconnecting is attempted before failing.
+    pub async fn send_query(&mut self, msg: &Message) -> Result<QueryResponse, CoreError> {
+        if self.is_disconnected() {
+            return Err(CoreError::NotConnected);
+        }
+
+        let _ = self.queries_sent.fetch_add(1, Ordering::SeqCst);
+        #[cfg(feature = "metrics")]
+        self.metrics.record_query_sent();
+
+        let request_timeout = self.request_timeout;
+        let response = with_request_timeout(request_timeout, self.deliver_query(msg)).await?;
+
+        #[cfg(feature = "metrics")]
+        self.metrics.record_query_response(&response);
+
+        self.record_success().await;
+        Ok(response)
+    }
+
+    // Deliver a query to the transport, or else to the Elders.
+    async fn deliver_query(&mut self, msg: &Message) -> Result<QueryResponse, CoreError> {
+        if let Some(transport) = self.transport.clone() {
+            return transport.send_query(msg).await;
+        }
+
+        let config = self.reconnect_config;
+        with_reconnect(
+            self,
+            config,
+            |cm| {
+                let msg = msg.clone();
+                async move { cm.try_send_query(&msg).await }.boxed()
+            },
+            |cm| cm.reconnect().boxed(),
+        )
+        .await
+    }
+
+    // Remember that the network was just reached successfully.
+    async fn record_success(&self) {
+        *self.last_success.lock().await = Some(Instant::now());
+    }
+
+    // Drop the connections to the Elders, and bootstrap again.
+    async fn reconnect(&mut self) -> Result<(), CoreError> {
+        if self.transport.is_some() {
+            return Ok(());
+        }
+
+        info!("Reconnecting to the network");
+        self.elders.clear();
+        self.bootstrap().await
+    }
+
+    // Send a command to all Elders, failing with `ConnectionLost` if none of them could be reached.
+    async fn try_send_cmd(&mut self, msg: &Message) -> Result<(), CoreError> {
         info!("Sending command message {:?} w/ id: {:?}", msg, msg.id());
         let msg_bytes = self.serialise_in_envelope(msg)?;
 
@@ -79,17 +344,29 @@ impl ConnectionManager {
         }
 
         // Let's await for all messages to be sent
-        let _results = join_all(tasks).await;
+        let results = join_all(tasks).await;
 
         // TODO: return an error if we didn't successfully
         // send it to at least a majority of Elders??
+        let sent = results
+            .iter()
+            .filter(|result| matches!(result, Ok(Ok(()))))
+            .count();
+        if sent == 0 {
+            warn!("Failed to send command to any Elder");
+            return Err(CoreError::ConnectionLost);
+        }
 
         Ok(())
     }
 
-    /// Send a Query `Message` to the network awaiting for the response.
-    pub async fn send_query(&mut self, msg: &Message) -> Result<QueryResponse, CoreError> {
+    // Send a query to all Elders, failing with `ConnectionLost` if none of them responded.
+    async fn try_send_query(&mut self, msg: &Message) -> Result<QueryResponse, CoreError> {
         info!("Sending query message {:?} w/ id: {:?}", msg, msg.id());
+        if self.elders.is_empty() {
+            return Err(CoreError::ConnectionLost);
+        }
+
         let msg_bytes = self.serialise_in_envelope(msg)?;
 
         // We send the same message to all Elders concurrently,
@@ -175,6 +452,15 @@ impl ConnectionManager {
                     }
                 }
             }
+
+            if !has_elected_a_response && todo.is_empty() {
+                // None of the Elders could be reached
+                if vote_map.is_empty() {
+                    warn!("No response to Query from any Elder");
+                    return Err(CoreError::ConnectionLost);
+                }
+                break;
+            }
         }
 
         trace!(
@@ -336,3 +622,142 @@ impl ConnectionManager {
         // }
     }
 }
+
+// Fail with `RequestTimeout` should the request not complete within the timeout, if any.
+async fn with_request_timeout<T>(
+    request_timeout: Option<Duration>,
+    request: impl Future<Output = Result<T, CoreError>>,
+) -> Result<T, CoreError> {
+    match request_timeout {
+        Some(duration) => timeout(duration, request).await.map_err(|_| {
+            warn!("Request timed out after {:?}", duration);
+            CoreError::RequestTimeout
+        })?,
+        None => request.await,
+    }
+}
+
+// Run the operation, reconnecting with an exponential backoff and trying again
+// each time it fails because the connection was lost.
+async fn with_reconnect<C, T, Op, Re>(
+    state: &mut C,
+    config: ReconnectConfig,
+    mut operation: Op,
+    mut reconnect: Re,
+) -> Result<T, CoreError>
+where
+    Op: for<'a> FnMut(&'a mut C) -> BoxFuture<'a, Result<T, CoreError>>,
+    Re: for<'a> FnMut(&'a mut C) -> BoxFuture<'a, Result<(), CoreError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match operation(state).await {
+            Err(CoreError::ConnectionLost) if attempt < config.max_attempts => {
+                let delay = config.delay(attempt);
+                attempt += 1;
+                warn!(
+                    "Connection lost, reconnecting in {:?} (attempt {} of {})",
+                    delay, attempt, config.max_attempts
+                );
+                delay_for(delay).await;
+
+                if let Err(error) = reconnect(state).await {
+                    warn!("Failed to reconnect to the network: {}", error);
+                }
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fails the first `failures` sends as if the connection was lost, then succeeds.
+    struct FlakyConnection {
+        failures: usize,
+        sends: usize,
+        reconnects: usize,
+    }
+
+    impl FlakyConnection {
+        async fn send(&mut self) -> Result<usize, CoreError> {
+            self.sends += 1;
+            if self.sends <= self.failures {
+                Err(CoreError::ConnectionLost)
+            } else {
+                Ok(self.sends)
+            }
+        }
+
+        async fn reconnect(&mut self) -> Result<(), CoreError> {
+            self.reconnects += 1;
+            Ok(())
+        }
+    }
+
+    fn config(max_attempts: usize) -> ReconnectConfig {
+        ReconnectConfig {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+            max_attempts,
+        }
+    }
+
+    #[tokio::test]
+    async fn request_completes_after_reconnecting() -> Result<(), CoreError> {
+        let mut conn = FlakyConnection {
+            failures: 3,
+            sends: 0,
+            reconnects: 0,
+        };
+
+        let sends = with_reconnect(
+            &mut conn,
+            config(5),
+            |conn| conn.send().boxed(),
+            |conn| conn.reconnect().boxed(),
+        )
+        .await?;
+
+        assert_eq!(sends, 4);
+        assert_eq!(conn.reconnects, 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connection_lost_after_reconnect_attempts_exhausted() -> Result<(), CoreError> {
+        let mut conn = FlakyConnection {
+            failures: 10,
+            sends: 0,
+            reconnects: 0,
+        };
+
+        let res = with_reconnect(
+            &mut conn,
+            config(2),
+            |conn| conn.send().boxed(),
+            |conn| conn.reconnect().boxed(),
+        )
+        .await;
+
+        match res {
+            Err(CoreError::ConnectionLost) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+        assert_eq!(conn.sends, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reconnect_delay_backs_off_exponentially_up_to_cap() {
+        let config = config(5);
+        assert_eq!(config.delay(0), Duration::from_millis(1));
+        assert_eq!(config.delay(1), Duration::from_millis(2));
+        assert_eq!(config.delay(2), Duration::from_millis(4));
+        assert_eq!(config.delay(3), Duration::from_millis(4));
+    }
+}
@@ -7,10 +7,11 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 // use crate::self_encryption_storage::SEStorageError;
+use crate::client::sequence_apis::Scope;
 use bincode::Error as SerialisationError;
 use futures::channel::mpsc::SendError;
 use quic_p2p::Error as QuicP2pError;
-use safe_nd::Error as SndError;
+use safe_nd::{Error as SndError, PublicKey, QueryResponse, SequenceAction, SequenceUser};
 
 use std::error::Error as StdError;
 use std::fmt::{self, Debug, Display, Formatter};
@@ -53,6 +54,77 @@ pub enum CoreError {
     OperationAborted,
     /// The request has timed out.
     RequestTimeout,
+    /// The connection to the network was lost, and could not be re-established.
+    ConnectionLost,
+    /// The client was disconnected from the network.
+    NotConnected,
+    /// Access denied to a Sequence, for the action attempted.
+    SequenceAccessDenied {
+        /// Action which was denied.
+        action: SequenceAction,
+    },
+    /// The Sequence is of another scope than the one the operation applies to.
+    WrongSequenceScope {
+        /// Scope the operation applies to.
+        expected: Scope,
+    },
+    /// The user has no permissions entry in the current permissions of a Sequence.
+    NoSuchUserPermissions {
+        /// User without any permissions entry.
+        user: SequenceUser,
+    },
+    /// Received a response of another type than the one expected for the query.
+    UnexpectedQueryResponse {
+        /// Type of response expected.
+        expected: &'static str,
+        /// Type of the response received.
+        got: String,
+    },
+    /// The range of entries requested is invalid.
+    InvalidRange {
+        /// Why the range is invalid.
+        reason: String,
+    },
+    /// The Sequence was modified concurrently, and no longer has the number of entries expected.
+    ConcurrentModification {
+        /// Number of entries the Sequence actually has.
+        actual: u64,
+    },
+    /// Paying for a write failed, so nothing was written.
+    PaymentFailed {
+        /// Why the payment failed.
+        kind: PaymentFailureKind,
+    },
+    /// Only some of the operations of a Sequence transaction were applied by the network.
+    TransactionPartiallyApplied {
+        /// Number of operations applied, in order.
+        applied: usize,
+        /// Why the next operation failed.
+        reason: String,
+    },
+    /// The Sequence was deleted from the network since it was put in the local CRDT replica.
+    SequenceDeleted,
+    /// The entry is larger than the client accepts to append.
+    EntryTooLarge {
+        /// Size of the entry, in bytes.
+        size: usize,
+        /// Maximum size of an entry, in bytes.
+        max: usize,
+    },
+    /// Entry of a Sequence which couldn't be deserialised into the type requested.
+    Deserialization(String),
+    /// The session being imported was exported by a client with another key.
+    SessionKeyMismatch {
+        /// Key of the client which exported the session.
+        session_key: PublicKey,
+    },
+    /// The balance was moved to the new key, but the client couldn't switch to it.
+    KeyRotationIncomplete {
+        /// Key now holding the balance.
+        new_key: PublicKey,
+        /// Why switching to the new key failed.
+        reason: String,
+    },
     /// Configuration file error.
     ConfigError(serde_json::Error),
     /// Io error.
@@ -61,6 +133,47 @@ pub enum CoreError {
     QuicP2p(QuicP2pError),
 }
 
+/// Why paying for a write failed, as reported by `CoreError::PaymentFailed`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PaymentFailureKind {
+    /// The balance can't cover the price of the write.
+    InsufficientBalance,
+    /// The network didn't validate the payment in time.
+    ValidationTimeout,
+    /// The network couldn't be reached, or didn't reply in time to any other request.
+    Network,
+    /// Any other failure, described.
+    Other(String),
+}
+
+impl Display for PaymentFailureKind {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match *self {
+            Self::InsufficientBalance => write!(formatter, "insufficient balance"),
+            Self::ValidationTimeout => write!(formatter, "validation timed out"),
+            Self::Network => write!(formatter, "network error"),
+            Self::Other(ref reason) => write!(formatter, "{}", reason),
+        }
+    }
+}
+
+impl CoreError {
+    /// Error for a query answered with a response of another type than the `expected` one.
+    /// Only the type of the response received is kept, not its content.
+    pub fn unexpected_query_response(expected: &'static str, got: &QueryResponse) -> Self {
+        let got = query_response_kind(got);
+        Self::UnexpectedQueryResponse { expected, got }
+    }
+}
+
+// Name of the variant of a query response, without any of its content.
+pub(crate) fn query_response_kind(response: &QueryResponse) -> String {
+    format!("{:?}", response)
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
 impl<'a> From<&'a str> for CoreError {
     fn from(error: &'a str) -> Self {
         Self::Unexpected(error.to_string())
@@ -158,6 +271,71 @@ impl Debug for CoreError {
             Self::UnsuccessfulPwHash => write!(formatter, "CoreError::UnsuccessfulPwHash"),
             Self::OperationAborted => write!(formatter, "CoreError::OperationAborted"),
             Self::RequestTimeout => write!(formatter, "CoreError::RequestTimeout"),
+            Self::ConnectionLost => write!(formatter, "CoreError::ConnectionLost"),
+            Self::NotConnected => write!(formatter, "CoreError::NotConnected"),
+            Self::SequenceAccessDenied { ref action } => write!(
+                formatter,
+                "CoreError::SequenceAccessDenied {{ action: {:?} }}",
+                action
+            ),
+            Self::WrongSequenceScope { expected } => write!(
+                formatter,
+                "CoreError::WrongSequenceScope {{ expected: {:?} }}",
+                expected
+            ),
+            Self::NoSuchUserPermissions { ref user } => write!(
+                formatter,
+                "CoreError::NoSuchUserPermissions {{ user: {:?} }}",
+                user
+            ),
+            Self::UnexpectedQueryResponse { expected, ref got } => write!(
+                formatter,
+                "CoreError::UnexpectedQueryResponse {{ expected: {}, got: {} }}",
+                expected, got
+            ),
+            Self::InvalidRange { ref reason } => write!(
+                formatter,
+                "CoreError::InvalidRange {{ reason: {} }}",
+                reason
+            ),
+            Self::ConcurrentModification { actual } => write!(
+                formatter,
+                "CoreError::ConcurrentModification {{ actual: {} }}",
+                actual
+            ),
+            Self::PaymentFailed { ref kind } => {
+                write!(formatter, "CoreError::PaymentFailed {{ kind: {:?} }}", kind)
+            }
+            Self::TransactionPartiallyApplied {
+                applied,
+                ref reason,
+            } => write!(
+                formatter,
+                "CoreError::TransactionPartiallyApplied {{ applied: {}, reason: {} }}",
+                applied, reason
+            ),
+            Self::SequenceDeleted => write!(formatter, "CoreError::SequenceDeleted"),
+            Self::EntryTooLarge { size, max } => write!(
+                formatter,
+                "CoreError::EntryTooLarge {{ size: {}, max: {} }}",
+                size, max
+            ),
+            Self::Deserialization(ref reason) => {
+                write!(formatter, "CoreError::Deserialization -> {:?}", reason)
+            }
+            Self::SessionKeyMismatch { ref session_key } => write!(
+                formatter,
+                "CoreError::SessionKeyMismatch {{ session_key: {:?} }}",
+                session_key
+            ),
+            Self::KeyRotationIncomplete {
+                ref new_key,
+                ref reason,
+            } => write!(
+                formatter,
+                "CoreError::KeyRotationIncomplete {{ new_key: {:?}, reason: {} }}",
+                new_key, reason
+            ),
             Self::ConfigError(ref error) => {
                 write!(formatter, "CoreError::ConfigError -> {:?}", error)
             }
@@ -202,6 +380,65 @@ impl Display for CoreError {
             ),
             Self::OperationAborted => write!(formatter, "Blocking operation was cancelled"),
             Self::RequestTimeout => write!(formatter, "RequestTimeout"),
+            Self::ConnectionLost => write!(formatter, "Connection to the network lost"),
+            Self::NotConnected => write!(formatter, "Not connected to the network"),
+            Self::SequenceAccessDenied { ref action } => write!(
+                formatter,
+                "Access denied to Sequence for action {:?}",
+                action
+            ),
+            Self::WrongSequenceScope { expected } => write!(
+                formatter,
+                "Operation only applies to {:?} Sequences",
+                expected
+            ),
+            Self::NoSuchUserPermissions { ref user } => {
+                write!(formatter, "No permissions for {:?} in the Sequence", user)
+            }
+            Self::UnexpectedQueryResponse { expected, ref got } => write!(
+                formatter,
+                "Unexpected query response: expected {}, got {}",
+                expected, got
+            ),
+            Self::InvalidRange { ref reason } => write!(formatter, "Invalid range: {}", reason),
+            Self::ConcurrentModification { actual } => write!(
+                formatter,
+                "Concurrent modification: the Sequence now has {} entries",
+                actual
+            ),
+            Self::PaymentFailed { ref kind } => write!(formatter, "Payment failed: {}", kind),
+            Self::TransactionPartiallyApplied {
+                applied,
+                ref reason,
+            } => write!(
+                formatter,
+                "Transaction failed after {} operations were applied: {}",
+                applied, reason
+            ),
+            Self::SequenceDeleted => write!(formatter, "Sequence was deleted from the network"),
+            Self::EntryTooLarge { size, max } => write!(
+                formatter,
+                "Entry of {} bytes exceeds the maximum of {} bytes",
+                size, max
+            ),
+            Self::Deserialization(ref reason) => write!(
+                formatter,
+                "Entry couldn't be deserialised into the type requested: {}",
+                reason
+            ),
+            Self::SessionKeyMismatch { ref session_key } => write!(
+                formatter,
+                "Session was exported by a client with another key: {:?}",
+                session_key
+            ),
+            Self::KeyRotationIncomplete {
+                ref new_key,
+                ref reason,
+            } => write!(
+                formatter,
+                "Balance was moved to {:?}, but switching to that key failed: {}",
+                new_key, reason
+            ),
             Self::ConfigError(ref error) => write!(formatter, "Config file error: {}", error),
             Self::IoError(ref error) => write!(formatter, "Io error: {}", error),
             Self::QuicP2p(ref error) => write!(formatter, "QuicP2P error: {}", error),
@@ -219,3 +456,23 @@ impl StdError for CoreError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use safe_nd::Money;
+
+    #[test]
+    fn unexpected_query_response_carries_the_variant_received() {
+        // Say we asked for a Sequence and were sent a balance instead
+        let response = QueryResponse::GetBalance(Ok(Money::from_nano(1)));
+
+        match CoreError::unexpected_query_response("GetSequence", &response) {
+            CoreError::UnexpectedQueryResponse { expected, got } => {
+                assert_eq!(expected, "GetSequence");
+                assert_eq!(got, "GetBalance");
+            }
+            error => panic!("Unexpected error: {:?}", error),
+        }
+    }
+}
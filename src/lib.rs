@@ -35,9 +35,12 @@
 
 // Export public core interface.
 
-pub use self::client::{map_info, Client, MapInfo};
+pub use self::client::{map_info, Client, ClientConfig, ClientHandle, MapInfo};
 pub use self::config_handler::config_dir;
-pub use self::errors::CoreError;
+pub use self::connection_manager::{ConnectionStatus, MessageTransport, ReconnectConfig};
+pub use self::errors::{CoreError, PaymentFailureKind};
+#[cfg(feature = "metrics")]
+pub use self::metrics::MetricsSnapshot;
 // pub use self::network_event::{NetworkEvent, NetworkRx, NetworkTx};
 pub use self::utils::logging;
 pub use quic_p2p::Config as QuicP2pConfig;
@@ -53,4 +56,6 @@ pub mod utils;
 
 mod connection_manager;
 mod errors;
+#[cfg(feature = "metrics")]
+mod metrics;
 // mod network_event;
@@ -0,0 +1,89 @@
+// Copyright 2020 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::errors::query_response_kind;
+use safe_nd::QueryResponse;
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+/// Counters of the network operations made by a client, shared with all its clones.
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    cmds_sent: AtomicUsize,
+    queries_sent: AtomicUsize,
+    query_responses: Mutex<BTreeMap<String, usize>>,
+    sequence_cache_hits: AtomicUsize,
+    sequence_cache_misses: AtomicUsize,
+    payment_proofs_created: AtomicUsize,
+}
+
+/// Values of the metrics of a client at the time `Client::metrics_snapshot` was called.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Number of commands sent to the network.
+    pub cmds_sent: usize,
+    /// Number of queries sent to the network.
+    pub queries_sent: usize,
+    /// Number of responses received to queries, by type of response.
+    pub query_responses: BTreeMap<String, usize>,
+    /// Number of Sequences found in the local CRDT replica.
+    pub sequence_cache_hits: usize,
+    /// Number of Sequences not found in the local CRDT replica, or too old.
+    pub sequence_cache_misses: usize,
+    /// Number of payment proofs created for writes.
+    pub payment_proofs_created: usize,
+}
+
+impl Metrics {
+    pub fn record_cmd_sent(&self) {
+        let _ = self.cmds_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_query_sent(&self) {
+        let _ = self.queries_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_query_response(&self, response: &QueryResponse) {
+        if let Ok(mut responses) = self.query_responses.lock() {
+            *responses.entry(query_response_kind(response)).or_insert(0) += 1;
+        }
+    }
+
+    pub fn record_sequence_cache_lookup(&self, hit: bool) {
+        let counter = if hit {
+            &self.sequence_cache_hits
+        } else {
+            &self.sequence_cache_misses
+        };
+        let _ = counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_payment_proof_created(&self) {
+        let _ = self.payment_proofs_created.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            cmds_sent: self.cmds_sent.load(Ordering::Relaxed),
+            queries_sent: self.queries_sent.load(Ordering::Relaxed),
+            query_responses: self
+                .query_responses
+                .lock()
+                .map(|responses| responses.clone())
+                .unwrap_or_default(),
+            sequence_cache_hits: self.sequence_cache_hits.load(Ordering::Relaxed),
+            sequence_cache_misses: self.sequence_cache_misses.load(Ordering::Relaxed),
+            payment_proofs_created: self.payment_proofs_created.load(Ordering::Relaxed),
+        }
+    }
+}
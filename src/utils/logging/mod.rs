@@ -169,6 +169,13 @@ fn init_impl(show_thread_name: bool, op_file_name_override: Option<String>) -> R
     }
 }
 
+/// Forwards the records of the `log` macros to the `tracing` subscriber, for apps using one
+/// instead of the loggers initialised by this module. Without a `tracing` subscriber, the
+/// spans and events of `tracing` are output as `log` records instead.
+pub fn init_tracing_bridge() -> Result<(), String> {
+    tracing_log::LogTracer::init().map_err(|e| format!("{}", e))
+}
+
 /// Initialises the `env_logger` for output to a file and optionally to the console asynchronously.
 ///
 /// For further details, see the [module docs](index.html).
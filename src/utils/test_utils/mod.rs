@@ -7,11 +7,157 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::client::COST_OF_PUT;
+use crate::connection_manager::{ConnectionManager, MessageTransport};
+use crate::errors::CoreError;
 
-use safe_nd::{ClientFullId, Keypair, Money};
+use async_trait::async_trait;
+use futures::{future, lock::Mutex as AsyncMutex};
+use safe_nd::{ClientFullId, Keypair, Message, Money, Query, QueryResponse, TransferQuery};
+use std::sync::{Mutex, PoisonError};
+use threshold_crypto::{PublicKeySet, SecretKeySet};
 
 use unwrap::unwrap;
 
+type QueryHandler = Box<dyn Fn(&Query) -> Result<QueryResponse, CoreError> + Send + Sync>;
+
+/// A `MessageTransport` recording all the messages sent to it, and answering queries with
+/// the given handler instead of the network. Commands are always delivered successfully.
+pub struct MockTransport {
+    sent: Mutex<Vec<Message>>,
+    respond: QueryHandler,
+}
+
+impl MockTransport {
+    /// Create a transport answering each query with `respond`.
+    pub fn new<F>(respond: F) -> Self
+    where
+        F: Fn(&Query) -> Result<QueryResponse, CoreError> + Send + Sync + 'static,
+    {
+        Self {
+            sent: Mutex::new(Vec::new()),
+            respond: Box::new(respond),
+        }
+    }
+
+    /// Get the messages sent so far, commands and queries alike, oldest first.
+    pub fn sent_messages(&self) -> Vec<Message> {
+        self.sent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn record(&self, msg: &Message) {
+        self.sent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(msg.clone());
+    }
+}
+
+#[async_trait]
+impl MessageTransport for MockTransport {
+    async fn send_cmd(&self, msg: &Message) -> Result<(), CoreError> {
+        self.record(msg);
+        Ok(())
+    }
+
+    async fn send_query(&self, msg: &Message) -> Result<QueryResponse, CoreError> {
+        self.record(msg);
+        match msg {
+            Message::Query { query, .. } => (self.respond)(query),
+            _ => Err(CoreError::from("Not a query message")),
+        }
+    }
+}
+
+/// A `MessageTransport` never answering, but to the query for the replica keys sent while
+/// setting up a `Client`, as if the network stopped responding right after.
+pub struct UnresponsiveTransport {
+    replicas_pk_set: PublicKeySet,
+}
+
+impl Default for UnresponsiveTransport {
+    fn default() -> Self {
+        Self {
+            replicas_pk_set: SecretKeySet::random(0, &mut rand::thread_rng()).public_keys(),
+        }
+    }
+}
+
+#[async_trait]
+impl MessageTransport for UnresponsiveTransport {
+    async fn send_cmd(&self, _msg: &Message) -> Result<(), CoreError> {
+        future::pending().await
+    }
+
+    async fn send_query(&self, msg: &Message) -> Result<QueryResponse, CoreError> {
+        match msg {
+            Message::Query {
+                query: Query::Transfer(TransferQuery::GetReplicaKeys(_)),
+                ..
+            } => Ok(QueryResponse::GetReplicaKeys(Ok(self
+                .replicas_pk_set
+                .clone()))),
+            _ => future::pending().await,
+        }
+    }
+}
+
+type MessageFilter = Box<dyn Fn(&Message) -> bool + Send + Sync>;
+
+/// A `MessageTransport` delivering the messages to the network through the given connection
+/// manager, but failing the first few commands matching a filter with `CoreError::ConnectionLost`,
+/// as if the connection was lost while sending them.
+pub struct FlakyTransport {
+    connection_manager: AsyncMutex<ConnectionManager>,
+    remaining_failures: Mutex<usize>,
+    fails: MessageFilter,
+}
+
+impl FlakyTransport {
+    /// Create a transport failing the first `failures` commands for which `fails` is `true`.
+    pub fn new<F>(connection_manager: ConnectionManager, failures: usize, fails: F) -> Self
+    where
+        F: Fn(&Message) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            connection_manager: AsyncMutex::new(connection_manager),
+            remaining_failures: Mutex::new(failures),
+            fails: Box::new(fails),
+        }
+    }
+
+    /// Get how many of the matching commands are still to fail.
+    pub fn remaining_failures(&self) -> usize {
+        *self
+            .remaining_failures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl MessageTransport for FlakyTransport {
+    async fn send_cmd(&self, msg: &Message) -> Result<(), CoreError> {
+        if (self.fails)(msg) {
+            let mut remaining = self
+                .remaining_failures
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if *remaining > 0 {
+                *remaining -= 1;
+                return Err(CoreError::ConnectionLost);
+            }
+        }
+        self.connection_manager.lock().await.send_cmd(msg).await
+    }
+
+    async fn send_query(&self, msg: &Message) -> Result<QueryResponse, CoreError> {
+        self.connection_manager.lock().await.send_query(msg).await
+    }
+}
+
 /// Generates a random BLS secret and public keypair.
 pub fn gen_bls_keypair() -> Keypair {
     let mut rng = rand::thread_rng();